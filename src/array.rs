@@ -8,22 +8,33 @@ use std::fmt;
 pub struct ArrayMap {
     pub id: usize,
     pub map: HashMap<usize, Array>,
+    // array_id, class_name like "[I" or "[Ljava/lang/String;"
+    pub class_names: HashMap<usize, usize>,
 }
 impl ArrayMap {
     pub fn new() -> ArrayMap {
         ArrayMap {
             id: 0,
             map: HashMap::new(),
+            class_names: HashMap::new(),
         }
     }
 
-    pub fn add(&mut self, value: Array) -> usize {
+    pub fn add(&mut self, value: Array, class_name: usize) -> usize {
         let id = self.id;
         self.id += 1;
         self.map.insert(id, value);
+        self.class_names.insert(id, class_name);
         id
     }
 
+    pub fn get_class_name(&self, id: &usize) -> usize {
+        *self
+            .class_names
+            .get(id)
+            .expect("should exist class_name in array_map")
+    }
+
     pub fn get(&self, id: &usize) -> Option<&Array> {
         self.map.get(id)
     }
@@ -45,11 +56,44 @@ pub enum PrimitiveArrayType {
     TLong = 11,
 }
 
+pub fn primitive_array_class_name(type_index: usize) -> &'static str {
+    match type_index {
+        4 => "[Z",
+        5 => "[C",
+        6 => "[F",
+        7 => "[D",
+        8 => "[B",
+        9 => "[S",
+        10 => "[I",
+        11 => "[J",
+        _ => unreachable!("type_index range should 4 - 11"),
+    }
+}
+
+// the element class name of anewarray is used as it is
+pub fn object_array_class_name(class_name: &str) -> String {
+    if class_name.starts_with('[') {
+        format!("[{}", class_name)
+    } else {
+        format!("[L{};", class_name)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Array {
     Primitive(RefCell<Vec<(Item, Item)>>),
-    Array(RefCell<Vec<usize>>),
-    Custom(RefCell<Vec<usize>>),
+    // the elements are arrays. None is null
+    Nested(RefCell<Vec<Option<usize>>>),
+    Custom(RefCell<Vec<Option<usize>>>),
+}
+
+impl Array {
+    pub fn len(&self) -> usize {
+        match self {
+            Array::Primitive(items) => items.borrow().len(),
+            Array::Nested(ids) | Array::Custom(ids) => ids.borrow().len(),
+        }
+    }
 }
 
 impl fmt::Display for Array {
@@ -1,3 +1,5 @@
+pub mod annotation;
 pub mod code;
 pub mod defs;
 pub mod instruction;
+pub mod module;
@@ -0,0 +1,666 @@
+use crate::attribute::defs::write_attribute;
+use crate::utils::{extract_x_byte_as_usize, write_x_byte_as_usize};
+use std::fmt;
+
+// RuntimeVisibleAnnotations and RuntimeInvisibleAnnotations
+#[derive(Debug)]
+pub struct RuntimeAnnotations {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub num_annotations: usize,    // u2
+    pub annotations: Vec<Annotation>,
+}
+
+impl RuntimeAnnotations {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (RuntimeAnnotations, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (num_annotations, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (annotations, index) = extract_annotations(inputs, index, num_annotations);
+        (
+            RuntimeAnnotations {
+                attribute_name_index,
+                attribute_length,
+                num_annotations,
+                annotations,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.annotations.len(), 2);
+        write_annotations(&mut info, &self.annotations);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for RuntimeAnnotations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", join_annotations(&self.annotations))
+    }
+}
+
+// RuntimeVisibleParameterAnnotations and RuntimeInvisibleParameterAnnotations
+#[derive(Debug)]
+pub struct RuntimeParameterAnnotations {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub num_parameters: usize,     // u1
+    pub parameter_annotations: Vec<ParameterAnnotation>,
+}
+
+impl RuntimeParameterAnnotations {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (RuntimeParameterAnnotations, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (num_parameters, mut index) = extract_x_byte_as_usize(inputs, index, 1);
+        let mut parameter_annotations = Vec::with_capacity(num_parameters);
+        for _ in 0..num_parameters {
+            let (num_annotations, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (annotations, update_index) =
+                extract_annotations(inputs, update_index, num_annotations);
+            parameter_annotations.push(ParameterAnnotation {
+                num_annotations,
+                annotations,
+            });
+            index = update_index;
+        }
+        (
+            RuntimeParameterAnnotations {
+                attribute_name_index,
+                attribute_length,
+                num_parameters,
+                parameter_annotations,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.parameter_annotations.len(), 1);
+        for parameter in self.parameter_annotations.iter() {
+            write_x_byte_as_usize(&mut info, parameter.annotations.len(), 2);
+            write_annotations(&mut info, &parameter.annotations);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for RuntimeParameterAnnotations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parameter_strs: Vec<String> = self
+            .parameter_annotations
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                format!(
+                    "parameter {}: {}",
+                    index,
+                    join_annotations(&item.annotations)
+                )
+            })
+            .collect();
+        write!(f, "{}", parameter_strs.join("\n  "))
+    }
+}
+
+#[derive(Debug)]
+pub struct ParameterAnnotation {
+    pub num_annotations: usize, // u2
+    pub annotations: Vec<Annotation>,
+}
+
+// RuntimeVisibleTypeAnnotations and RuntimeInvisibleTypeAnnotations
+#[derive(Debug)]
+pub struct RuntimeTypeAnnotations {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub num_annotations: usize,    // u2
+    pub annotations: Vec<TypeAnnotation>,
+}
+
+impl RuntimeTypeAnnotations {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (RuntimeTypeAnnotations, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (num_annotations, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut annotations = Vec::with_capacity(num_annotations);
+        for _ in 0..num_annotations {
+            let (annotation, update_index) = TypeAnnotation::new(inputs, index);
+            annotations.push(annotation);
+            index = update_index;
+        }
+        (
+            RuntimeTypeAnnotations {
+                attribute_name_index,
+                attribute_length,
+                num_annotations,
+                annotations,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.annotations.len(), 2);
+        for annotation in self.annotations.iter() {
+            annotation.write(&mut info);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for RuntimeTypeAnnotations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let annotation_strs: Vec<String> = self
+            .annotations
+            .iter()
+            .enumerate()
+            .map(|(index, item)| format!("{}: {}", index, item))
+            .collect();
+        write!(f, "{}", annotation_strs.join("\n  "))
+    }
+}
+
+#[derive(Debug)]
+pub struct AnnotationDefault {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub default_value: ElementValue,
+}
+
+impl AnnotationDefault {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (AnnotationDefault, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (default_value, index) = ElementValue::new(inputs, index);
+        (
+            AnnotationDefault {
+                attribute_name_index,
+                attribute_length,
+                default_value,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        self.default_value.write(&mut info);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for AnnotationDefault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "AnnotationDefault:
+  default_value: {}",
+            self.default_value
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Annotation {
+    pub type_index: u16,                // u2
+    pub num_element_value_pairs: usize, // u2
+    pub element_value_pairs: Vec<ElementValuePair>,
+}
+
+impl Annotation {
+    pub fn new(inputs: &[u8], index: usize) -> (Annotation, usize) {
+        let (type_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let type_index = type_index as u16;
+
+        let (num_element_value_pairs, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (element_value_pairs, index) =
+            extract_element_value_pairs(inputs, index, num_element_value_pairs);
+        (
+            Annotation {
+                type_index,
+                num_element_value_pairs,
+                element_value_pairs,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_x_byte_as_usize(output, self.type_index as usize, 2);
+        write_element_value_pairs(output, &self.element_value_pairs);
+    }
+}
+
+impl fmt::Display for Annotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{}({})",
+            self.type_index,
+            join_element_value_pairs(&self.element_value_pairs)
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ElementValuePair {
+    pub element_name_index: u16, // u2
+    pub value: ElementValue,
+}
+
+impl fmt::Display for ElementValuePair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}={}", self.element_name_index, self.value)
+    }
+}
+
+#[derive(Debug)]
+pub enum ElementValue {
+    ConstValue(u8, u16),           // B C D F I J S Z s, u2(const_value_index)
+    EnumConstValue(u16, u16),      // e, u2(type_name_index) u2(const_name_index)
+    ClassInfo(u16),                // c, u2(class_info_index)
+    AnnotationValue(Annotation),   // @
+    ArrayValue(Vec<ElementValue>), // [, u2(num_values)
+}
+
+impl ElementValue {
+    pub fn new(inputs: &[u8], index: usize) -> (ElementValue, usize) {
+        let (tag, index) = extract_x_byte_as_usize(inputs, index, 1);
+        match tag as u8 {
+            b'B' | b'C' | b'D' | b'F' | b'I' | b'J' | b'S' | b'Z' | b's' => {
+                let (const_value_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+                (
+                    ElementValue::ConstValue(tag as u8, const_value_index as u16),
+                    index,
+                )
+            }
+            b'e' => {
+                let (type_name_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let (const_name_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+                (
+                    ElementValue::EnumConstValue(type_name_index as u16, const_name_index as u16),
+                    index,
+                )
+            }
+            b'c' => {
+                let (class_info_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+                (ElementValue::ClassInfo(class_info_index as u16), index)
+            }
+            b'@' => {
+                let (annotation, index) = Annotation::new(inputs, index);
+                (ElementValue::AnnotationValue(annotation), index)
+            }
+            b'[' => {
+                let (num_values, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+                let mut values = Vec::with_capacity(num_values);
+                for _ in 0..num_values {
+                    let (value, update_index) = ElementValue::new(inputs, index);
+                    values.push(value);
+                    index = update_index;
+                }
+                (ElementValue::ArrayValue(values), index)
+            }
+            _ => unreachable!("element_value tag {} is not defined", tag),
+        }
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        match self {
+            ElementValue::ConstValue(tag, const_value_index) => {
+                output.push(*tag);
+                write_x_byte_as_usize(output, *const_value_index as usize, 2);
+            }
+            ElementValue::EnumConstValue(type_name_index, const_name_index) => {
+                output.push(b'e');
+                write_x_byte_as_usize(output, *type_name_index as usize, 2);
+                write_x_byte_as_usize(output, *const_name_index as usize, 2);
+            }
+            ElementValue::ClassInfo(class_info_index) => {
+                output.push(b'c');
+                write_x_byte_as_usize(output, *class_info_index as usize, 2);
+            }
+            ElementValue::AnnotationValue(annotation) => {
+                output.push(b'@');
+                annotation.write(output);
+            }
+            ElementValue::ArrayValue(values) => {
+                output.push(b'[');
+                write_x_byte_as_usize(output, values.len(), 2);
+                for value in values.iter() {
+                    value.write(output);
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for ElementValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ElementValue::ConstValue(tag, index) => write!(f, "{}#{}", *tag as char, index),
+            ElementValue::EnumConstValue(type_name_index, const_name_index) => {
+                write!(f, "e#{}.#{}", type_name_index, const_name_index)
+            }
+            ElementValue::ClassInfo(index) => write!(f, "c#{}", index),
+            ElementValue::AnnotationValue(annotation) => write!(f, "@{}", annotation),
+            ElementValue::ArrayValue(values) => write!(
+                f,
+                "[{}]",
+                values
+                    .iter()
+                    .map(|value| format!("{}", value))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TypeAnnotation {
+    pub target_type: u8, // u1
+    pub target_info: TargetInfo,
+    pub target_path: TypePath,
+    pub type_index: u16,                // u2
+    pub num_element_value_pairs: usize, // u2
+    pub element_value_pairs: Vec<ElementValuePair>,
+}
+
+impl TypeAnnotation {
+    pub fn new(inputs: &[u8], index: usize) -> (TypeAnnotation, usize) {
+        let (target_type, index) = extract_x_byte_as_usize(inputs, index, 1);
+        let target_type = target_type as u8;
+
+        let (target_info, index) = TargetInfo::new(inputs, index, target_type);
+        let (target_path, index) = TypePath::new(inputs, index);
+
+        let (type_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let type_index = type_index as u16;
+
+        let (num_element_value_pairs, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (element_value_pairs, index) =
+            extract_element_value_pairs(inputs, index, num_element_value_pairs);
+        (
+            TypeAnnotation {
+                target_type,
+                target_info,
+                target_path,
+                type_index,
+                num_element_value_pairs,
+                element_value_pairs,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        output.push(self.target_type);
+        self.target_info.write(output);
+        self.target_path.write(output);
+        write_x_byte_as_usize(output, self.type_index as usize, 2);
+        write_element_value_pairs(output, &self.element_value_pairs);
+    }
+}
+
+impl fmt::Display for TypeAnnotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{}({}): 0x{:02x}",
+            self.type_index,
+            join_element_value_pairs(&self.element_value_pairs),
+            self.target_type
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum TargetInfo {
+    TypeParameter(u8),             // 0x00-0x01, u1(type_parameter_index)
+    Supertype(u16),                // 0x10, u2(supertype_index)
+    TypeParameterBound(u8, u8),    // 0x11-0x12, u1(type_parameter_index) u1(bound_index)
+    Empty,                         // 0x13-0x15
+    FormalParameter(u8),           // 0x16, u1(formal_parameter_index)
+    Throws(u16),                   // 0x17, u2(throws_type_index)
+    Localvar(Vec<LocalvarTarget>), // 0x40-0x41, u2(table_length)
+    Catch(u16),                    // 0x42, u2(exception_table_index)
+    Offset(u16),                   // 0x43-0x46, u2(offset)
+    TypeArgument(u16, u8),         // 0x47-0x4b, u2(offset) u1(type_argument_index)
+}
+
+impl TargetInfo {
+    pub fn new(inputs: &[u8], index: usize, target_type: u8) -> (TargetInfo, usize) {
+        match target_type {
+            0x00 | 0x01 => {
+                let (type_parameter_index, index) = extract_x_byte_as_usize(inputs, index, 1);
+                (TargetInfo::TypeParameter(type_parameter_index as u8), index)
+            }
+            0x10 => {
+                let (supertype_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+                (TargetInfo::Supertype(supertype_index as u16), index)
+            }
+            0x11 | 0x12 => {
+                let (type_parameter_index, index) = extract_x_byte_as_usize(inputs, index, 1);
+                let (bound_index, index) = extract_x_byte_as_usize(inputs, index, 1);
+                (
+                    TargetInfo::TypeParameterBound(type_parameter_index as u8, bound_index as u8),
+                    index,
+                )
+            }
+            0x13..=0x15 => (TargetInfo::Empty, index),
+            0x16 => {
+                let (formal_parameter_index, index) = extract_x_byte_as_usize(inputs, index, 1);
+                (
+                    TargetInfo::FormalParameter(formal_parameter_index as u8),
+                    index,
+                )
+            }
+            0x17 => {
+                let (throws_type_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+                (TargetInfo::Throws(throws_type_index as u16), index)
+            }
+            0x40 | 0x41 => {
+                let (table_length, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+                let mut table = Vec::with_capacity(table_length);
+                for _ in 0..table_length {
+                    let (start_pc, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+                    let (length, update_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+                    let (local_index, update_index) =
+                        extract_x_byte_as_usize(inputs, update_index, 2);
+                    table.push(LocalvarTarget {
+                        start_pc: start_pc as u16,
+                        length: length as u16,
+                        index: local_index as u16,
+                    });
+                    index = update_index;
+                }
+                (TargetInfo::Localvar(table), index)
+            }
+            0x42 => {
+                let (exception_table_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+                (TargetInfo::Catch(exception_table_index as u16), index)
+            }
+            0x43..=0x46 => {
+                let (offset, index) = extract_x_byte_as_usize(inputs, index, 2);
+                (TargetInfo::Offset(offset as u16), index)
+            }
+            0x47..=0x4b => {
+                let (offset, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let (type_argument_index, index) = extract_x_byte_as_usize(inputs, index, 1);
+                (
+                    TargetInfo::TypeArgument(offset as u16, type_argument_index as u8),
+                    index,
+                )
+            }
+            _ => unreachable!("target_type 0x{:02x} is not defined", target_type),
+        }
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        match self {
+            TargetInfo::TypeParameter(type_parameter_index) => output.push(*type_parameter_index),
+            TargetInfo::Supertype(supertype_index) => {
+                write_x_byte_as_usize(output, *supertype_index as usize, 2);
+            }
+            TargetInfo::TypeParameterBound(type_parameter_index, bound_index) => {
+                output.push(*type_parameter_index);
+                output.push(*bound_index);
+            }
+            TargetInfo::Empty => {}
+            TargetInfo::FormalParameter(formal_parameter_index) => {
+                output.push(*formal_parameter_index)
+            }
+            TargetInfo::Throws(throws_type_index) => {
+                write_x_byte_as_usize(output, *throws_type_index as usize, 2);
+            }
+            TargetInfo::Localvar(table) => {
+                write_x_byte_as_usize(output, table.len(), 2);
+                for item in table.iter() {
+                    write_x_byte_as_usize(output, item.start_pc as usize, 2);
+                    write_x_byte_as_usize(output, item.length as usize, 2);
+                    write_x_byte_as_usize(output, item.index as usize, 2);
+                }
+            }
+            TargetInfo::Catch(exception_table_index) => {
+                write_x_byte_as_usize(output, *exception_table_index as usize, 2);
+            }
+            TargetInfo::Offset(offset) => write_x_byte_as_usize(output, *offset as usize, 2),
+            TargetInfo::TypeArgument(offset, type_argument_index) => {
+                write_x_byte_as_usize(output, *offset as usize, 2);
+                output.push(*type_argument_index);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LocalvarTarget {
+    pub start_pc: u16, // u2
+    pub length: u16,   // u2
+    pub index: u16,    // u2
+}
+
+#[derive(Debug)]
+pub struct TypePath {
+    pub path_length: usize, // u1
+    pub path: Vec<TypePathItem>,
+}
+
+impl TypePath {
+    pub fn new(inputs: &[u8], index: usize) -> (TypePath, usize) {
+        let (path_length, mut index) = extract_x_byte_as_usize(inputs, index, 1);
+        let mut path = Vec::with_capacity(path_length);
+        for _ in 0..path_length {
+            let (type_path_kind, update_index) = extract_x_byte_as_usize(inputs, index, 1);
+            let (type_argument_index, update_index) =
+                extract_x_byte_as_usize(inputs, update_index, 1);
+            path.push(TypePathItem {
+                type_path_kind: type_path_kind as u8,
+                type_argument_index: type_argument_index as u8,
+            });
+            index = update_index;
+        }
+        (TypePath { path_length, path }, index)
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_x_byte_as_usize(output, self.path.len(), 1);
+        for item in self.path.iter() {
+            output.push(item.type_path_kind);
+            output.push(item.type_argument_index);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TypePathItem {
+    pub type_path_kind: u8,      // u1
+    pub type_argument_index: u8, // u1
+}
+
+fn extract_annotations(inputs: &[u8], index: usize, length: usize) -> (Vec<Annotation>, usize) {
+    let mut index = index;
+    let mut annotations = Vec::with_capacity(length);
+    for _ in 0..length {
+        let (annotation, update_index) = Annotation::new(inputs, index);
+        annotations.push(annotation);
+        index = update_index;
+    }
+    (annotations, index)
+}
+
+fn extract_element_value_pairs(
+    inputs: &[u8],
+    index: usize,
+    length: usize,
+) -> (Vec<ElementValuePair>, usize) {
+    let mut index = index;
+    let mut pairs = Vec::with_capacity(length);
+    for _ in 0..length {
+        let (element_name_index, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (value, update_index) = ElementValue::new(inputs, update_index);
+        pairs.push(ElementValuePair {
+            element_name_index: element_name_index as u16,
+            value,
+        });
+        index = update_index;
+    }
+    (pairs, index)
+}
+
+fn write_annotations(output: &mut Vec<u8>, annotations: &[Annotation]) {
+    for annotation in annotations.iter() {
+        annotation.write(output);
+    }
+}
+
+fn write_element_value_pairs(output: &mut Vec<u8>, pairs: &[ElementValuePair]) {
+    write_x_byte_as_usize(output, pairs.len(), 2);
+    for pair in pairs.iter() {
+        write_x_byte_as_usize(output, pair.element_name_index as usize, 2);
+        pair.value.write(output);
+    }
+}
+
+fn join_annotations(annotations: &[Annotation]) -> String {
+    annotations
+        .iter()
+        .enumerate()
+        .map(|(index, annotation)| format!("{}: {}", index, annotation))
+        .collect::<Vec<String>>()
+        .join("\n  ")
+}
+
+fn join_element_value_pairs(pairs: &[ElementValuePair]) -> String {
+    pairs
+        .iter()
+        .map(|pair| format!("{}", pair))
+        .collect::<Vec<String>>()
+        .join(",")
+}
@@ -1,8 +1,8 @@
-use crate::attribute::defs::Attribute;
+use crate::attribute::defs::{write_attribute, Attribute};
 use crate::attribute::instruction::Instruction;
 use crate::constant::ConstantPool;
 use crate::string_pool::StringPool;
-use crate::utils::extract_x_byte_as_usize;
+use crate::utils::{extract_x_byte_as_usize, write_x_byte_as_usize};
 use std::fmt;
 
 #[derive(Debug)]
@@ -48,8 +48,13 @@ impl Code {
             index = update_index;
         }
 
-        let (exception_table_length, index) = extract_x_byte_as_usize(inputs, index, 2);
-        let exception_table = Vec::with_capacity(exception_table_length);
+        let (exception_table_length, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut exception_table = Vec::with_capacity(exception_table_length);
+        for _ in 0..exception_table_length {
+            let (item, update_index) = ExceptionTableItem::new(inputs, index);
+            index = update_index;
+            exception_table.push(item);
+        }
 
         let (attributes_count, mut index) = extract_x_byte_as_usize(inputs, index, 2);
         let mut attribute_info = Vec::with_capacity(attributes_count);
@@ -76,6 +81,30 @@ impl Code {
             index,
         )
     }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.max_stack as usize, 2);
+        write_x_byte_as_usize(&mut info, self.max_locals as usize, 2);
+
+        let mut code = Vec::with_capacity(self.code_length);
+        for (pc, instruction) in self.code.iter().enumerate() {
+            instruction.write(&mut code, pc);
+        }
+        write_x_byte_as_usize(&mut info, code.len(), 4);
+        info.extend(code);
+
+        write_x_byte_as_usize(&mut info, self.exception_table.len(), 2);
+        for item in self.exception_table.iter() {
+            item.write(&mut info);
+        }
+
+        write_x_byte_as_usize(&mut info, self.attribute_info.len(), 2);
+        for attribute in self.attribute_info.iter() {
+            attribute.write(&mut info);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
 }
 
 impl fmt::Display for Code {
@@ -113,3 +142,33 @@ pub struct ExceptionTableItem {
     pub handler_pc: u16, //u2
     pub catch_type: u16, //u2
 }
+
+impl ExceptionTableItem {
+    pub fn new(inputs: &[u8], index: usize) -> (ExceptionTableItem, usize) {
+        let (start_pc, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (end_pc, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (handler_pc, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (catch_type, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            ExceptionTableItem {
+                start_pc: start_pc as u16,
+                end_pc: end_pc as u16,
+                handler_pc: handler_pc as u16,
+                catch_type: catch_type as u16,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_x_byte_as_usize(output, self.start_pc as usize, 2);
+        write_x_byte_as_usize(output, self.end_pc as usize, 2);
+        write_x_byte_as_usize(output, self.handler_pc as usize, 2);
+        write_x_byte_as_usize(output, self.catch_type as usize, 2);
+    }
+
+    // end_pc is exclusive
+    pub fn is_in_range(&self, index: usize) -> bool {
+        (self.start_pc as usize) <= index && index < (self.end_pc as usize)
+    }
+}
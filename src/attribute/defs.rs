@@ -1,35 +1,48 @@
+use crate::attribute::annotation::{
+    AnnotationDefault, RuntimeAnnotations, RuntimeParameterAnnotations, RuntimeTypeAnnotations,
+};
 use crate::attribute::code::Code;
+use crate::attribute::module::{Module, ModuleMainClass, ModulePackages};
 use crate::constant::{ConstPoolItem, ConstantPool};
 use crate::string_pool::StringPool;
-use crate::utils::extract_x_byte_as_usize;
+use crate::utils::{extract_x_byte_as_usize, extract_x_byte_as_vec, write_x_byte_as_usize};
 
 use std::fmt;
 
 #[derive(Debug)]
 pub enum Attribute {
     SourceFile(SourceFile),
-    InnerClasses,
-    EnclosingMethod,
-    SourceDebugExtension,
-    BootstrapMethods,
-    ConstantValue,
+    InnerClasses(InnerClasses),
+    EnclosingMethod(EnclosingMethod),
+    SourceDebugExtension(SourceDebugExtension),
+    BootstrapMethods(BootstrapMethods),
+    ConstantValue(ConstantValue),
     Code(Code),
-    Exceptions,
-    RuntimeVisibleParameterAnnotations,
-    RuntimeInvisibleParameterAnnotations,
-    AnnotationDefault,
-    MethodParameters,
-    Synthetic,
-    Deprecated,
-    Signature,
-    RuntimeVisibleAnnotations,
-    RuntimeInvisibleAnnotations,
+    Exceptions(Exceptions),
+    RuntimeVisibleParameterAnnotations(RuntimeParameterAnnotations),
+    RuntimeInvisibleParameterAnnotations(RuntimeParameterAnnotations),
+    AnnotationDefault(AnnotationDefault),
+    MethodParameters(MethodParameters),
+    Synthetic(Synthetic),
+    Deprecated(Deprecated),
+    Signature(Signature),
+    RuntimeVisibleAnnotations(RuntimeAnnotations),
+    RuntimeInvisibleAnnotations(RuntimeAnnotations),
     LineNumberTable(LineNumberTable),
-    LocalVariableTable,
-    LocalVariableTypeTable,
+    LocalVariableTable(LocalVariableTable),
+    LocalVariableTypeTable(LocalVariableTypeTable),
     StackMapTable(StackMapTable),
-    RuntimeVisibleTypeAnnotations,
-    RuntimeInvisibleTypeAnnotations,
+    RuntimeVisibleTypeAnnotations(RuntimeTypeAnnotations),
+    RuntimeInvisibleTypeAnnotations(RuntimeTypeAnnotations),
+    NestHost(NestHost),
+    NestMembers(NestMembers),
+    Record(Record),
+    PermittedSubclasses(PermittedSubclasses),
+    Module(Module),
+    ModulePackages(ModulePackages),
+    ModuleMainClass(ModuleMainClass),
+    // attributes which are not defined in JVMS are skipped by attribute_length
+    Unknown(Unknown),
 }
 
 impl Attribute {
@@ -66,7 +79,130 @@ impl Attribute {
                     );
                     (Attribute::Code(item), index)
                 }
-                _ => unimplemented!(),
+                AttributeTag::InnerClasses => {
+                    let (item, index) = InnerClasses::new(inputs, index, attribute_name_index);
+                    (Attribute::InnerClasses(item), index)
+                }
+                AttributeTag::EnclosingMethod => {
+                    let (item, index) = EnclosingMethod::new(inputs, index, attribute_name_index);
+                    (Attribute::EnclosingMethod(item), index)
+                }
+                AttributeTag::SourceDebugExtension => {
+                    let (item, index) =
+                        SourceDebugExtension::new(inputs, index, attribute_name_index);
+                    (Attribute::SourceDebugExtension(item), index)
+                }
+                AttributeTag::BootstrapMethods => {
+                    let (item, index) = BootstrapMethods::new(inputs, index, attribute_name_index);
+                    (Attribute::BootstrapMethods(item), index)
+                }
+                AttributeTag::ConstantValue => {
+                    let (item, index) = ConstantValue::new(inputs, index, attribute_name_index);
+                    (Attribute::ConstantValue(item), index)
+                }
+                AttributeTag::Exceptions => {
+                    let (item, index) = Exceptions::new(inputs, index, attribute_name_index);
+                    (Attribute::Exceptions(item), index)
+                }
+                AttributeTag::RuntimeVisibleParameterAnnotations => {
+                    let (item, index) =
+                        RuntimeParameterAnnotations::new(inputs, index, attribute_name_index);
+                    (Attribute::RuntimeVisibleParameterAnnotations(item), index)
+                }
+                AttributeTag::RuntimeInvisibleParameterAnnotations => {
+                    let (item, index) =
+                        RuntimeParameterAnnotations::new(inputs, index, attribute_name_index);
+                    (Attribute::RuntimeInvisibleParameterAnnotations(item), index)
+                }
+                AttributeTag::AnnotationDefault => {
+                    let (item, index) = AnnotationDefault::new(inputs, index, attribute_name_index);
+                    (Attribute::AnnotationDefault(item), index)
+                }
+                AttributeTag::MethodParameters => {
+                    let (item, index) = MethodParameters::new(inputs, index, attribute_name_index);
+                    (Attribute::MethodParameters(item), index)
+                }
+                AttributeTag::Synthetic => {
+                    let (item, index) = Synthetic::new(inputs, index, attribute_name_index);
+                    (Attribute::Synthetic(item), index)
+                }
+                AttributeTag::Deprecated => {
+                    let (item, index) = Deprecated::new(inputs, index, attribute_name_index);
+                    (Attribute::Deprecated(item), index)
+                }
+                AttributeTag::Signature => {
+                    let (item, index) = Signature::new(inputs, index, attribute_name_index);
+                    (Attribute::Signature(item), index)
+                }
+                AttributeTag::RuntimeVisibleAnnotations => {
+                    let (item, index) =
+                        RuntimeAnnotations::new(inputs, index, attribute_name_index);
+                    (Attribute::RuntimeVisibleAnnotations(item), index)
+                }
+                AttributeTag::RuntimeInvisibleAnnotations => {
+                    let (item, index) =
+                        RuntimeAnnotations::new(inputs, index, attribute_name_index);
+                    (Attribute::RuntimeInvisibleAnnotations(item), index)
+                }
+                AttributeTag::LocalVariableTable => {
+                    let (item, index) =
+                        LocalVariableTable::new(inputs, index, attribute_name_index);
+                    (Attribute::LocalVariableTable(item), index)
+                }
+                AttributeTag::LocalVariableTypeTable => {
+                    let (item, index) =
+                        LocalVariableTypeTable::new(inputs, index, attribute_name_index);
+                    (Attribute::LocalVariableTypeTable(item), index)
+                }
+                AttributeTag::RuntimeVisibleTypeAnnotations => {
+                    let (item, index) =
+                        RuntimeTypeAnnotations::new(inputs, index, attribute_name_index);
+                    (Attribute::RuntimeVisibleTypeAnnotations(item), index)
+                }
+                AttributeTag::RuntimeInvisibleTypeAnnotations => {
+                    let (item, index) =
+                        RuntimeTypeAnnotations::new(inputs, index, attribute_name_index);
+                    (Attribute::RuntimeInvisibleTypeAnnotations(item), index)
+                }
+                AttributeTag::NestHost => {
+                    let (item, index) = NestHost::new(inputs, index, attribute_name_index);
+                    (Attribute::NestHost(item), index)
+                }
+                AttributeTag::NestMembers => {
+                    let (item, index) = NestMembers::new(inputs, index, attribute_name_index);
+                    (Attribute::NestMembers(item), index)
+                }
+                AttributeTag::Record => {
+                    let (item, index) = Record::new(
+                        string_pool,
+                        constant_pool,
+                        inputs,
+                        index,
+                        attribute_name_index,
+                    );
+                    (Attribute::Record(item), index)
+                }
+                AttributeTag::PermittedSubclasses => {
+                    let (item, index) =
+                        PermittedSubclasses::new(inputs, index, attribute_name_index);
+                    (Attribute::PermittedSubclasses(item), index)
+                }
+                AttributeTag::Module => {
+                    let (item, index) = Module::new(inputs, index, attribute_name_index);
+                    (Attribute::Module(item), index)
+                }
+                AttributeTag::ModulePackages => {
+                    let (item, index) = ModulePackages::new(inputs, index, attribute_name_index);
+                    (Attribute::ModulePackages(item), index)
+                }
+                AttributeTag::ModuleMainClass => {
+                    let (item, index) = ModuleMainClass::new(inputs, index, attribute_name_index);
+                    (Attribute::ModuleMainClass(item), index)
+                }
+                AttributeTag::Unknown => {
+                    let (item, index) = Unknown::new(inputs, index, attribute_name_index);
+                    (Attribute::Unknown(item), index)
+                }
             }
         } else {
             panic!(
@@ -75,110 +211,1135 @@ impl Attribute {
             );
         }
     }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        match self {
+            Attribute::SourceFile(item) => item.write(output),
+            Attribute::InnerClasses(item) => item.write(output),
+            Attribute::EnclosingMethod(item) => item.write(output),
+            Attribute::SourceDebugExtension(item) => item.write(output),
+            Attribute::BootstrapMethods(item) => item.write(output),
+            Attribute::ConstantValue(item) => item.write(output),
+            Attribute::Code(item) => item.write(output),
+            Attribute::Exceptions(item) => item.write(output),
+            Attribute::RuntimeVisibleParameterAnnotations(item) => item.write(output),
+            Attribute::RuntimeInvisibleParameterAnnotations(item) => item.write(output),
+            Attribute::AnnotationDefault(item) => item.write(output),
+            Attribute::MethodParameters(item) => item.write(output),
+            Attribute::Synthetic(item) => item.write(output),
+            Attribute::Deprecated(item) => item.write(output),
+            Attribute::Signature(item) => item.write(output),
+            Attribute::RuntimeVisibleAnnotations(item) => item.write(output),
+            Attribute::RuntimeInvisibleAnnotations(item) => item.write(output),
+            Attribute::LineNumberTable(item) => item.write(output),
+            Attribute::LocalVariableTable(item) => item.write(output),
+            Attribute::LocalVariableTypeTable(item) => item.write(output),
+            Attribute::StackMapTable(item) => item.write(output),
+            Attribute::RuntimeVisibleTypeAnnotations(item) => item.write(output),
+            Attribute::RuntimeInvisibleTypeAnnotations(item) => item.write(output),
+            Attribute::NestHost(item) => item.write(output),
+            Attribute::NestMembers(item) => item.write(output),
+            Attribute::Record(item) => item.write(output),
+            Attribute::PermittedSubclasses(item) => item.write(output),
+            Attribute::Module(item) => item.write(output),
+            Attribute::ModulePackages(item) => item.write(output),
+            Attribute::ModuleMainClass(item) => item.write(output),
+            Attribute::Unknown(item) => item.write(output),
+        }
+    }
+}
+
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Attribute::SourceFile(val) => write!(f, "{}", val),
+            Attribute::InnerClasses(val) => write!(f, "{}", val),
+            Attribute::EnclosingMethod(val) => write!(f, "{}", val),
+            Attribute::SourceDebugExtension(val) => write!(f, "{}", val),
+            Attribute::BootstrapMethods(val) => write!(f, "{}", val),
+            Attribute::ConstantValue(val) => write!(f, "{}", val),
+            Attribute::Code(val) => write!(f, "{}", val),
+            Attribute::Exceptions(val) => write!(f, "{}", val),
+            Attribute::AnnotationDefault(val) => write!(f, "{}", val),
+            Attribute::MethodParameters(val) => write!(f, "{}", val),
+            Attribute::Synthetic(val) => write!(f, "{}", val),
+            Attribute::Deprecated(val) => write!(f, "{}", val),
+            Attribute::Signature(val) => write!(f, "{}", val),
+            Attribute::LineNumberTable(val) => write!(f, "{}", val),
+            Attribute::LocalVariableTable(val) => write!(f, "{}", val),
+            Attribute::LocalVariableTypeTable(val) => write!(f, "{}", val),
+            Attribute::StackMapTable(val) => write!(f, "{}", val),
+            Attribute::NestHost(val) => write!(f, "{}", val),
+            Attribute::NestMembers(val) => write!(f, "{}", val),
+            Attribute::Record(val) => write!(f, "{}", val),
+            Attribute::PermittedSubclasses(val) => write!(f, "{}", val),
+            Attribute::Module(val) => write!(f, "{}", val),
+            Attribute::ModulePackages(val) => write!(f, "{}", val),
+            Attribute::ModuleMainClass(val) => write!(f, "{}", val),
+            Attribute::Unknown(val) => write!(f, "{}", val),
+            Attribute::RuntimeVisibleParameterAnnotations(val) => {
+                write!(f, "RuntimeVisibleParameterAnnotations:\n  {}", val)
+            }
+            Attribute::RuntimeInvisibleParameterAnnotations(val) => {
+                write!(f, "RuntimeInvisibleParameterAnnotations:\n  {}", val)
+            }
+            Attribute::RuntimeVisibleAnnotations(val) => {
+                write!(f, "RuntimeVisibleAnnotations:\n  {}", val)
+            }
+            Attribute::RuntimeInvisibleAnnotations(val) => {
+                write!(f, "RuntimeInvisibleAnnotations:\n  {}", val)
+            }
+            Attribute::RuntimeVisibleTypeAnnotations(val) => {
+                write!(f, "RuntimeVisibleTypeAnnotations:\n  {}", val)
+            }
+            Attribute::RuntimeInvisibleTypeAnnotations(val) => {
+                write!(f, "RuntimeInvisibleTypeAnnotations:\n  {}", val)
+            }
+        }
+    }
+}
+
+// this is a custom enum for handling
+#[derive(Debug)]
+pub enum AttributeTag {
+    SourceFile,
+    InnerClasses,
+    EnclosingMethod,
+    SourceDebugExtension,
+    BootstrapMethods,
+    ConstantValue,
+    Code,
+    Exceptions,
+    RuntimeVisibleParameterAnnotations,
+    RuntimeInvisibleParameterAnnotations,
+    AnnotationDefault,
+    MethodParameters,
+    Synthetic,
+    Deprecated,
+    Signature,
+    RuntimeVisibleAnnotations,
+    RuntimeInvisibleAnnotations,
+    LineNumberTable,
+    LocalVariableTable,
+    LocalVariableTypeTable,
+    StackMapTable,
+    RuntimeVisibleTypeAnnotations,
+    RuntimeInvisibleTypeAnnotations,
+    NestHost,
+    NestMembers,
+    Record,
+    PermittedSubclasses,
+    Module,
+    ModulePackages,
+    ModuleMainClass,
+    Unknown,
+}
+
+impl From<String> for AttributeTag {
+    fn from(input: String) -> AttributeTag {
+        match input.as_str() {
+            "SourceFile" => AttributeTag::SourceFile,
+            "InnerClasses" => AttributeTag::InnerClasses,
+            "EnclosingMethod" => AttributeTag::EnclosingMethod,
+            "SourceDebugExtension" => AttributeTag::SourceDebugExtension,
+            "BootstrapMethods" => AttributeTag::BootstrapMethods,
+            "ConstantValue" => AttributeTag::ConstantValue,
+            "Code" => AttributeTag::Code,
+            "Exceptions" => AttributeTag::Exceptions,
+            "RuntimeVisibleParameterAnnotations" => {
+                AttributeTag::RuntimeVisibleParameterAnnotations
+            }
+            "RuntimeInvisibleParameterAnnotations" => {
+                AttributeTag::RuntimeInvisibleParameterAnnotations
+            }
+            "AnnotationDefault" => AttributeTag::AnnotationDefault,
+            "MethodParameters" => AttributeTag::MethodParameters,
+            "Synthetic" => AttributeTag::Synthetic,
+            "Deprecated" => AttributeTag::Deprecated,
+            "Signature" => AttributeTag::Signature,
+            "RuntimeVisibleAnnotations" => AttributeTag::RuntimeVisibleAnnotations,
+            "RuntimeInvisibleAnnotations" => AttributeTag::RuntimeInvisibleAnnotations,
+            "LineNumberTable" => AttributeTag::LineNumberTable,
+            "LocalVariableTable" => AttributeTag::LocalVariableTable,
+            "LocalVariableTypeTable" => AttributeTag::LocalVariableTypeTable,
+            "StackMapTable" => AttributeTag::StackMapTable,
+            "RuntimeVisibleTypeAnnotations" => AttributeTag::RuntimeVisibleTypeAnnotations,
+            "RuntimeInvisibleTypeAnnotations" => AttributeTag::RuntimeInvisibleTypeAnnotations,
+            "NestHost" => AttributeTag::NestHost,
+            "NestMembers" => AttributeTag::NestMembers,
+            "Record" => AttributeTag::Record,
+            "PermittedSubclasses" => AttributeTag::PermittedSubclasses,
+            "Module" => AttributeTag::Module,
+            "ModulePackages" => AttributeTag::ModulePackages,
+            "ModuleMainClass" => AttributeTag::ModuleMainClass,
+            _ => AttributeTag::Unknown,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SourceFile {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub sourcefile_index: u16,     // u2
+}
+
+impl SourceFile {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (SourceFile, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (sourcefile_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let sourcefile_index = sourcefile_index as u16;
+
+        let source_file = SourceFile {
+            attribute_name_index,
+            attribute_length,
+            sourcefile_index,
+        };
+        (source_file, index)
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.sourcefile_index as usize, 2);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for SourceFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SourceFile: #{}", self.sourcefile_index)
+    }
+}
+
+#[derive(Debug)]
+pub struct InnerClasses {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub number_of_classes: usize,  // u2
+    pub classes: Vec<InnerClass>,
+}
+
+impl InnerClasses {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (InnerClasses, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (number_of_classes, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut classes = Vec::with_capacity(number_of_classes);
+        for _ in 0..number_of_classes {
+            let (inner_class_info_index, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (outer_class_info_index, update_index) =
+                extract_x_byte_as_usize(inputs, update_index, 2);
+            let (inner_name_index, update_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+            let (inner_class_access_flags, update_index) =
+                extract_x_byte_as_usize(inputs, update_index, 2);
+            classes.push(InnerClass {
+                inner_class_info_index: inner_class_info_index as u16,
+                outer_class_info_index: outer_class_info_index as u16,
+                inner_name_index: inner_name_index as u16,
+                inner_class_access_flags: inner_class_access_flags as u16,
+            });
+            index = update_index;
+        }
+        (
+            InnerClasses {
+                attribute_name_index,
+                attribute_length,
+                number_of_classes,
+                classes,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.classes.len(), 2);
+        for class in self.classes.iter() {
+            write_x_byte_as_usize(&mut info, class.inner_class_info_index as usize, 2);
+            write_x_byte_as_usize(&mut info, class.outer_class_info_index as usize, 2);
+            write_x_byte_as_usize(&mut info, class.inner_name_index as usize, 2);
+            write_x_byte_as_usize(&mut info, class.inner_class_access_flags as usize, 2);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for InnerClasses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut class_strs = Vec::with_capacity(self.number_of_classes);
+        for item in self.classes.iter() {
+            class_strs.push(format!("{}", item));
+        }
+        write!(
+            f,
+            "InnerClasses:
+  {}",
+            class_strs.join("\n  ")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct InnerClass {
+    pub inner_class_info_index: u16,   // u2
+    pub outer_class_info_index: u16,   // u2
+    pub inner_name_index: u16,         // u2
+    pub inner_class_access_flags: u16, // u2
+}
+
+impl fmt::Display for InnerClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{}= #{} of #{}; flags: 0x{:04x}",
+            self.inner_name_index,
+            self.inner_class_info_index,
+            self.outer_class_info_index,
+            self.inner_class_access_flags
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct EnclosingMethod {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub class_index: u16,          // u2
+    pub method_index: u16,         // u2
+}
+
+impl EnclosingMethod {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (EnclosingMethod, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (class_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (method_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            EnclosingMethod {
+                attribute_name_index,
+                attribute_length,
+                class_index: class_index as u16,
+                method_index: method_index as u16,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.class_index as usize, 2);
+        write_x_byte_as_usize(&mut info, self.method_index as usize, 2);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for EnclosingMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "EnclosingMethod: #{}.#{}",
+            self.class_index, self.method_index
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct SourceDebugExtension {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub debug_extension: Vec<u8>,  // u1 debug_extension[attribute_length]
+}
+
+impl SourceDebugExtension {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (SourceDebugExtension, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let (debug_extension, index) = extract_x_byte_as_vec(inputs, index, attribute_length);
+        (
+            SourceDebugExtension {
+                attribute_name_index,
+                attribute_length: attribute_length as u32,
+                debug_extension,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_attribute(output, self.attribute_name_index, &self.debug_extension);
+    }
+}
+
+impl fmt::Display for SourceDebugExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SourceDebugExtension:
+  {}",
+            String::from_utf8_lossy(self.debug_extension.as_slice())
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct BootstrapMethods {
+    pub attribute_name_index: u16,    // u2
+    pub attribute_length: u32,        // u4
+    pub num_bootstrap_methods: usize, // u2
+    pub bootstrap_methods: Vec<BootstrapMethod>,
+}
+
+impl BootstrapMethods {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (BootstrapMethods, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (num_bootstrap_methods, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut bootstrap_methods = Vec::with_capacity(num_bootstrap_methods);
+        for _ in 0..num_bootstrap_methods {
+            let (bootstrap_method_ref, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (num_bootstrap_arguments, update_index) =
+                extract_x_byte_as_usize(inputs, update_index, 2);
+            let (bootstrap_arguments, update_index) =
+                extract_u2_indexes(inputs, update_index, num_bootstrap_arguments);
+            bootstrap_methods.push(BootstrapMethod {
+                bootstrap_method_ref: bootstrap_method_ref as u16,
+                num_bootstrap_arguments,
+                bootstrap_arguments,
+            });
+            index = update_index;
+        }
+        (
+            BootstrapMethods {
+                attribute_name_index,
+                attribute_length,
+                num_bootstrap_methods,
+                bootstrap_methods,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.bootstrap_methods.len(), 2);
+        for method in self.bootstrap_methods.iter() {
+            write_x_byte_as_usize(&mut info, method.bootstrap_method_ref as usize, 2);
+            write_x_byte_as_usize(&mut info, method.bootstrap_arguments.len(), 2);
+            write_u2_indexes(&mut info, &method.bootstrap_arguments);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for BootstrapMethods {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut method_strs = Vec::with_capacity(self.num_bootstrap_methods);
+        for (index, item) in self.bootstrap_methods.iter().enumerate() {
+            method_strs.push(format!("{}: {}", index, item));
+        }
+        write!(
+            f,
+            "BootstrapMethods:
+  {}",
+            method_strs.join("\n  ")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct BootstrapMethod {
+    pub bootstrap_method_ref: u16,      // u2
+    pub num_bootstrap_arguments: usize, // u2
+    pub bootstrap_arguments: Vec<u16>,  // u2 bootstrap_arguments[num_bootstrap_arguments]
+}
+
+impl fmt::Display for BootstrapMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{} {}",
+            self.bootstrap_method_ref,
+            join_indexes(&self.bootstrap_arguments)
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Exceptions {
+    pub attribute_name_index: u16,       // u2
+    pub attribute_length: u32,           // u4
+    pub number_of_exceptions: usize,     // u2
+    pub exception_index_table: Vec<u16>, // u2 exception_index_table[number_of_exceptions]
+}
+
+impl Exceptions {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (Exceptions, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (number_of_exceptions, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (exception_index_table, index) =
+            extract_u2_indexes(inputs, index, number_of_exceptions);
+        (
+            Exceptions {
+                attribute_name_index,
+                attribute_length,
+                number_of_exceptions,
+                exception_index_table,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.exception_index_table.len(), 2);
+        write_u2_indexes(&mut info, &self.exception_index_table);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for Exceptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Exceptions:
+  throws {}",
+            join_indexes(&self.exception_index_table)
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct MethodParameters {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub parameters_count: usize,   // u1
+    pub parameters: Vec<MethodParameter>,
+}
+
+impl MethodParameters {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (MethodParameters, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (parameters_count, mut index) = extract_x_byte_as_usize(inputs, index, 1);
+        let mut parameters = Vec::with_capacity(parameters_count);
+        for _ in 0..parameters_count {
+            let (name_index, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (access_flags, update_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+            parameters.push(MethodParameter {
+                name_index: name_index as u16,
+                access_flags: access_flags as u16,
+            });
+            index = update_index;
+        }
+        (
+            MethodParameters {
+                attribute_name_index,
+                attribute_length,
+                parameters_count,
+                parameters,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.parameters.len(), 1);
+        for parameter in self.parameters.iter() {
+            write_x_byte_as_usize(&mut info, parameter.name_index as usize, 2);
+            write_x_byte_as_usize(&mut info, parameter.access_flags as usize, 2);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for MethodParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parameter_strs = Vec::with_capacity(self.parameters_count);
+        for item in self.parameters.iter() {
+            parameter_strs.push(format!(
+                "#{} flags: 0x{:04x}",
+                item.name_index, item.access_flags
+            ));
+        }
+        write!(
+            f,
+            "MethodParameters:
+  {}",
+            parameter_strs.join("\n  ")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct MethodParameter {
+    pub name_index: u16,   // u2
+    pub access_flags: u16, // u2
+}
+
+#[derive(Debug)]
+pub struct Synthetic {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+}
+
+impl Synthetic {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (Synthetic, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        (
+            Synthetic {
+                attribute_name_index,
+                attribute_length: attribute_length as u32,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_attribute(output, self.attribute_name_index, &[]);
+    }
+}
+
+impl fmt::Display for Synthetic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Synthetic: true")
+    }
+}
+
+#[derive(Debug)]
+pub struct Deprecated {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+}
+
+impl Deprecated {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (Deprecated, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        (
+            Deprecated {
+                attribute_name_index,
+                attribute_length: attribute_length as u32,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_attribute(output, self.attribute_name_index, &[]);
+    }
+}
+
+impl fmt::Display for Deprecated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Deprecated: true")
+    }
+}
+
+#[derive(Debug)]
+pub struct Signature {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub signature_index: u16,      // u2
+}
+
+impl Signature {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (Signature, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (signature_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            Signature {
+                attribute_name_index,
+                attribute_length,
+                signature_index: signature_index as u16,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.signature_index as usize, 2);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Signature: #{}", self.signature_index)
+    }
+}
+
+#[derive(Debug)]
+pub struct LocalVariableTable {
+    pub attribute_name_index: u16,          // u2
+    pub attribute_length: u32,              // u4
+    pub local_variable_table_length: usize, // u2
+    pub local_variable_table: Vec<LocalVariableTableItem>,
+}
+
+impl LocalVariableTable {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (LocalVariableTable, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (local_variable_table_length, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut local_variable_table = Vec::with_capacity(local_variable_table_length);
+        for _ in 0..local_variable_table_length {
+            let (item, update_index) = LocalVariableTableItem::new(inputs, index);
+            local_variable_table.push(item);
+            index = update_index;
+        }
+        (
+            LocalVariableTable {
+                attribute_name_index,
+                attribute_length,
+                local_variable_table_length,
+                local_variable_table,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.local_variable_table.len(), 2);
+        for item in self.local_variable_table.iter() {
+            item.write(&mut info);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for LocalVariableTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table_strs = Vec::with_capacity(self.local_variable_table_length);
+        for item in self.local_variable_table.iter() {
+            table_strs.push(format!("{}", item));
+        }
+        write!(
+            f,
+            "LocalVariableTable:
+  Start  Length  Slot  Name   Signature
+  {}",
+            table_strs.join("\n  ")
+        )
+    }
+}
+
+// descriptor_index is signature_index in LocalVariableTypeTable
+#[derive(Debug)]
+pub struct LocalVariableTableItem {
+    pub start_pc: u16,         // u2
+    pub length: u16,           // u2
+    pub name_index: u16,       // u2
+    pub descriptor_index: u16, // u2
+    pub index: u16,            // u2
+}
+
+impl LocalVariableTableItem {
+    pub fn new(inputs: &[u8], index: usize) -> (LocalVariableTableItem, usize) {
+        let (start_pc, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (length, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (name_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (descriptor_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (local_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            LocalVariableTableItem {
+                start_pc: start_pc as u16,
+                length: length as u16,
+                name_index: name_index as u16,
+                descriptor_index: descriptor_index as u16,
+                index: local_index as u16,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_x_byte_as_usize(output, self.start_pc as usize, 2);
+        write_x_byte_as_usize(output, self.length as usize, 2);
+        write_x_byte_as_usize(output, self.name_index as usize, 2);
+        write_x_byte_as_usize(output, self.descriptor_index as usize, 2);
+        write_x_byte_as_usize(output, self.index as usize, 2);
+    }
+}
+
+impl fmt::Display for LocalVariableTableItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>5}  {:>6}  {:>4}  #{:<4}  #{}",
+            self.start_pc, self.length, self.index, self.name_index, self.descriptor_index
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct LocalVariableTypeTable {
+    pub attribute_name_index: u16,               // u2
+    pub attribute_length: u32,                   // u4
+    pub local_variable_type_table_length: usize, // u2
+    pub local_variable_type_table: Vec<LocalVariableTableItem>,
+}
+
+impl LocalVariableTypeTable {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (LocalVariableTypeTable, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (local_variable_type_table_length, mut index) =
+            extract_x_byte_as_usize(inputs, index, 2);
+        let mut local_variable_type_table = Vec::with_capacity(local_variable_type_table_length);
+        for _ in 0..local_variable_type_table_length {
+            let (item, update_index) = LocalVariableTableItem::new(inputs, index);
+            local_variable_type_table.push(item);
+            index = update_index;
+        }
+        (
+            LocalVariableTypeTable {
+                attribute_name_index,
+                attribute_length,
+                local_variable_type_table_length,
+                local_variable_type_table,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.local_variable_type_table.len(), 2);
+        for item in self.local_variable_type_table.iter() {
+            item.write(&mut info);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for LocalVariableTypeTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table_strs = Vec::with_capacity(self.local_variable_type_table_length);
+        for item in self.local_variable_type_table.iter() {
+            table_strs.push(format!("{}", item));
+        }
+        write!(
+            f,
+            "LocalVariableTypeTable:
+  Start  Length  Slot  Name   Signature
+  {}",
+            table_strs.join("\n  ")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct NestHost {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub host_class_index: u16,     // u2
+}
+
+impl NestHost {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (NestHost, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (host_class_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            NestHost {
+                attribute_name_index,
+                attribute_length,
+                host_class_index: host_class_index as u16,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.host_class_index as usize, 2);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for NestHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NestHost: #{}", self.host_class_index)
+    }
+}
+
+#[derive(Debug)]
+pub struct NestMembers {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub number_of_classes: usize,  // u2
+    pub classes: Vec<u16>,         // u2 classes[number_of_classes]
+}
+
+impl NestMembers {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (NestMembers, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (number_of_classes, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (classes, index) = extract_u2_indexes(inputs, index, number_of_classes);
+        (
+            NestMembers {
+                attribute_name_index,
+                attribute_length,
+                number_of_classes,
+                classes,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.classes.len(), 2);
+        write_u2_indexes(&mut info, &self.classes);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for NestMembers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NestMembers: {}", join_indexes(&self.classes))
+    }
+}
+
+#[derive(Debug)]
+pub struct Record {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub components_count: usize,   // u2
+    pub components: Vec<RecordComponent>,
+}
+
+impl Record {
+    pub fn new(
+        string_pool: &mut StringPool,
+        constant_pool: &ConstantPool,
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (Record, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (components_count, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut components = Vec::with_capacity(components_count);
+        for _ in 0..components_count {
+            let (name_index, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (descriptor_index, update_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+            let (attributes_count, mut update_index) =
+                extract_x_byte_as_usize(inputs, update_index, 2);
+            let mut attributes = Vec::with_capacity(attributes_count);
+            for _ in 0..attributes_count {
+                let (attribute, attribute_index) =
+                    Attribute::new(string_pool, constant_pool, inputs, update_index);
+                attributes.push(attribute);
+                update_index = attribute_index;
+            }
+            components.push(RecordComponent {
+                name_index: name_index as u16,
+                descriptor_index: descriptor_index as u16,
+                attributes_count,
+                attributes,
+            });
+            index = update_index;
+        }
+        (
+            Record {
+                attribute_name_index,
+                attribute_length,
+                components_count,
+                components,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.components.len(), 2);
+        for component in self.components.iter() {
+            write_x_byte_as_usize(&mut info, component.name_index as usize, 2);
+            write_x_byte_as_usize(&mut info, component.descriptor_index as usize, 2);
+            write_x_byte_as_usize(&mut info, component.attributes.len(), 2);
+            for attribute in component.attributes.iter() {
+                attribute.write(&mut info);
+            }
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
 }
 
-impl fmt::Display for Attribute {
+impl fmt::Display for Record {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Attribute::SourceFile(val) => write!(f, "{}", val),
-            Attribute::Code(val) => write!(f, "{}", val),
-            Attribute::LineNumberTable(val) => write!(f, "{}", val),
-            Attribute::StackMapTable(val) => write!(f, "{}", val),
-            _ => unimplemented!(),
+        let mut component_strs = Vec::with_capacity(self.components_count);
+        for item in self.components.iter() {
+            component_strs.push(format!("#{}: #{}", item.name_index, item.descriptor_index));
         }
+        write!(
+            f,
+            "Record:
+  {}",
+            component_strs.join("\n  ")
+        )
     }
 }
 
-// this is a custom enum for handling
 #[derive(Debug)]
-pub enum AttributeTag {
-    SourceFile,
-    InnerClasses,
-    EnclosingMethod,
-    SourceDebugExtension,
-    BootstrapMethods,
-    ConstantValue,
-    Code,
-    Exceptions,
-    RuntimeVisibleParameterAnnotations,
-    RuntimeInvisibleParameterAnnotations,
-    AnnotationDefault,
-    MethodParameters,
-    Synthetic,
-    Deprecated,
-    Signature,
-    RuntimeVisibleAnnotations,
-    RuntimeInvisibleAnnotations,
-    LineNumberTable,
-    LocalVariableTable,
-    LocalVariableTypeTable,
-    StackMapTable,
-    RuntimeVisibleTypeAnnotations,
-    RuntimeInvisibleTypeAnnotations,
+pub struct RecordComponent {
+    pub name_index: u16,         // u2
+    pub descriptor_index: u16,   // u2
+    pub attributes_count: usize, // u2
+    pub attributes: Vec<Attribute>,
 }
 
-impl From<String> for AttributeTag {
-    fn from(input: String) -> AttributeTag {
-        match input.as_str() {
-            "SourceFile" => AttributeTag::SourceFile,
-            "InnerClasses" => AttributeTag::InnerClasses,
-            "EnclosingMethod" => AttributeTag::EnclosingMethod,
-            "SourceDebugExtension" => AttributeTag::SourceDebugExtension,
-            "BootstrapMethods" => AttributeTag::BootstrapMethods,
-            "ConstantValue" => AttributeTag::ConstantValue,
-            "Code" => AttributeTag::Code,
-            "Exceptions" => AttributeTag::Exceptions,
-            "RuntimeVisibleParameterAnnotations" => {
-                AttributeTag::RuntimeVisibleParameterAnnotations
-            }
-            "RuntimeInvisibleParameterAnnotations" => {
-                AttributeTag::RuntimeInvisibleParameterAnnotations
-            }
-            "AnnotationDefault" => AttributeTag::AnnotationDefault,
-            "MethodParameters" => AttributeTag::MethodParameters,
-            "Synthetic" => AttributeTag::Synthetic,
-            "Deprecated" => AttributeTag::Deprecated,
-            "Signature" => AttributeTag::Signature,
-            "RuntimeVisibleAnnotations" => AttributeTag::RuntimeVisibleAnnotations,
-            "RuntimeInvisibleAnnotations" => AttributeTag::RuntimeInvisibleAnnotations,
-            "LineNumberTable" => AttributeTag::LineNumberTable,
-            "LocalVariableTable" => AttributeTag::LocalVariableTable,
-            "LocalVariableTypeTable" => AttributeTag::LocalVariableTypeTable,
-            "StackMapTable" => AttributeTag::StackMapTable,
-            "RuntimeVisibleTypeAnnotations" => AttributeTag::RuntimeVisibleTypeAnnotations,
-            "RuntimeInvisibleTypeAnnotations" => AttributeTag::RuntimeInvisibleTypeAnnotations,
-            _ => unreachable!(),
-        }
+#[derive(Debug)]
+pub struct PermittedSubclasses {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub number_of_classes: usize,  // u2
+    pub classes: Vec<u16>,         // u2 classes[number_of_classes]
+}
+
+impl PermittedSubclasses {
+    pub fn new(
+        inputs: &[u8],
+        index: usize,
+        attribute_name_index: u16,
+    ) -> (PermittedSubclasses, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (number_of_classes, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (classes, index) = extract_u2_indexes(inputs, index, number_of_classes);
+        (
+            PermittedSubclasses {
+                attribute_name_index,
+                attribute_length,
+                number_of_classes,
+                classes,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.classes.len(), 2);
+        write_u2_indexes(&mut info, &self.classes);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for PermittedSubclasses {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PermittedSubclasses: {}", join_indexes(&self.classes))
     }
 }
 
 #[derive(Debug)]
-pub struct SourceFile {
+pub struct Unknown {
     pub attribute_name_index: u16, // u2
     pub attribute_length: u32,     // u4
-    pub sourcefile_index: u16,     // u2
+    pub info: Vec<u8>,             // u1 info[attribute_length]
 }
 
-impl SourceFile {
-    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (SourceFile, usize) {
+impl Unknown {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (Unknown, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let (info, index) = extract_x_byte_as_vec(inputs, index, attribute_length);
+        (
+            Unknown {
+                attribute_name_index,
+                attribute_length: attribute_length as u32,
+                info,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_attribute(output, self.attribute_name_index, &self.info);
+    }
+}
+
+impl fmt::Display for Unknown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unknown: #{} length = {}",
+            self.attribute_name_index, self.attribute_length
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ConstantValue {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub constantvalue_index: u16,  // u2
+}
+
+impl ConstantValue {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (ConstantValue, usize) {
         let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
         let attribute_length = attribute_length as u32;
 
-        let (sourcefile_index, index) = extract_x_byte_as_usize(inputs, index, 2);
-        let sourcefile_index = sourcefile_index as u16;
+        let (constantvalue_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let constantvalue_index = constantvalue_index as u16;
 
-        let source_file = SourceFile {
+        let constant_value = ConstantValue {
             attribute_name_index,
             attribute_length,
-            sourcefile_index,
+            constantvalue_index,
         };
-        (source_file, index)
+        (constant_value, index)
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.constantvalue_index as usize, 2);
+        write_attribute(output, self.attribute_name_index, &info);
     }
 }
 
-impl fmt::Display for SourceFile {
+impl fmt::Display for ConstantValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "SourceFile: #{}", self.sourcefile_index)
+        write!(f, "ConstantValue: #{}", self.constantvalue_index)
     }
 }
 
@@ -222,6 +1383,16 @@ impl LineNumberTable {
             index,
         )
     }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.line_number_tables.len(), 2);
+        for item in self.line_number_tables.iter() {
+            write_x_byte_as_usize(&mut info, item.start_pc as usize, 2);
+            write_x_byte_as_usize(&mut info, item.line_number as usize, 2);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
 }
 
 impl fmt::Display for LineNumberTable {
@@ -282,6 +1453,15 @@ impl StackMapTable {
             index,
         )
     }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.stack_map_frame.len(), 2);
+        for frame in self.stack_map_frame.iter() {
+            frame.write(&mut info);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
 }
 
 impl fmt::Display for StackMapTable {
@@ -304,10 +1484,10 @@ impl fmt::Display for StackMapTable {
 #[derive(Debug)]
 pub enum StackMapFrame {
     SameFrame(SameFrame),
-    SameLocals1StackItemFrame,
-    SameLocals1StackItemFrameExtended,
+    SameLocals1StackItemFrame(SameLocals1StackItemFrame),
+    SameLocals1StackItemFrameExtended(SameLocals1StackItemFrameExtended),
     ChopFrame(ChopFrame),
-    SameFrameExtended,
+    SameFrameExtended(SameFrameExtended),
     AppendFrame(AppendFrame),
     FullFrame(FullFrame),
 }
@@ -317,6 +1497,25 @@ pub struct SameFrame {
     frame_type: usize,
 }
 
+#[derive(Debug)]
+pub struct SameLocals1StackItemFrame {
+    frame_type: usize,
+    stack: Vec<VerificationTypeInfo>, // stack[1]
+}
+
+#[derive(Debug)]
+pub struct SameLocals1StackItemFrameExtended {
+    frame_type: usize,
+    offset_delta: usize,              // u2
+    stack: Vec<VerificationTypeInfo>, // stack[1]
+}
+
+#[derive(Debug)]
+pub struct SameFrameExtended {
+    frame_type: usize,
+    offset_delta: usize, // u2
+}
+
 #[derive(Debug)]
 pub struct ChopFrame {
     frame_type: usize,
@@ -345,6 +1544,30 @@ impl StackMapFrame {
         let (frame_type, index) = extract_x_byte_as_usize(inputs, index, 1);
         match frame_type {
             0..=63 => (StackMapFrame::SameFrame(SameFrame { frame_type }), index),
+            64..=127 => {
+                let (stack, index) = extract_verification_type_info(inputs, index, 1);
+                (
+                    StackMapFrame::SameLocals1StackItemFrame(SameLocals1StackItemFrame {
+                        frame_type,
+                        stack,
+                    }),
+                    index,
+                )
+            }
+            247 => {
+                let (offset_delta, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let (stack, index) = extract_verification_type_info(inputs, index, 1);
+                (
+                    StackMapFrame::SameLocals1StackItemFrameExtended(
+                        SameLocals1StackItemFrameExtended {
+                            frame_type,
+                            offset_delta,
+                            stack,
+                        },
+                    ),
+                    index,
+                )
+            }
             248..=250 => {
                 let (offset_delta, index) = extract_x_byte_as_usize(inputs, index, 2);
                 (
@@ -355,6 +1578,16 @@ impl StackMapFrame {
                     index,
                 )
             }
+            251 => {
+                let (offset_delta, index) = extract_x_byte_as_usize(inputs, index, 2);
+                (
+                    StackMapFrame::SameFrameExtended(SameFrameExtended {
+                        frame_type,
+                        offset_delta,
+                    }),
+                    index,
+                )
+            }
             252..=254 => {
                 let (offset_delta, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let length = (frame_type as i32) - 251;
@@ -394,7 +1627,67 @@ impl StackMapFrame {
                     index,
                 )
             }
-            _ => unimplemented!(),
+            _ => unreachable!("frame_type {} is reserved", frame_type),
+        }
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        match self {
+            StackMapFrame::SameFrame(SameFrame { frame_type }) => {
+                write_x_byte_as_usize(output, *frame_type, 1);
+            }
+            StackMapFrame::SameLocals1StackItemFrame(SameLocals1StackItemFrame {
+                frame_type,
+                stack,
+            }) => {
+                write_x_byte_as_usize(output, *frame_type, 1);
+                write_verification_type_info(output, stack);
+            }
+            StackMapFrame::SameLocals1StackItemFrameExtended(
+                SameLocals1StackItemFrameExtended {
+                    frame_type,
+                    offset_delta,
+                    stack,
+                },
+            ) => {
+                write_x_byte_as_usize(output, *frame_type, 1);
+                write_x_byte_as_usize(output, *offset_delta, 2);
+                write_verification_type_info(output, stack);
+            }
+            StackMapFrame::ChopFrame(ChopFrame {
+                frame_type,
+                offset_delta,
+            })
+            | StackMapFrame::SameFrameExtended(SameFrameExtended {
+                frame_type,
+                offset_delta,
+            }) => {
+                write_x_byte_as_usize(output, *frame_type, 1);
+                write_x_byte_as_usize(output, *offset_delta, 2);
+            }
+            StackMapFrame::AppendFrame(AppendFrame {
+                frame_type,
+                offset_delta,
+                locals,
+            }) => {
+                write_x_byte_as_usize(output, *frame_type, 1);
+                write_x_byte_as_usize(output, *offset_delta, 2);
+                write_verification_type_info(output, locals);
+            }
+            StackMapFrame::FullFrame(FullFrame {
+                frame_type,
+                offset_delta,
+                locals,
+                stack,
+                ..
+            }) => {
+                write_x_byte_as_usize(output, *frame_type, 1);
+                write_x_byte_as_usize(output, *offset_delta, 2);
+                write_x_byte_as_usize(output, locals.len(), 2);
+                write_verification_type_info(output, locals);
+                write_x_byte_as_usize(output, stack.len(), 2);
+                write_verification_type_info(output, stack);
+            }
         }
     }
 }
@@ -405,6 +1698,37 @@ impl fmt::Display for StackMapFrame {
             StackMapFrame::SameFrame(SameFrame { frame_type }) => {
                 write!(f, "{}   /* same */", frame_type)
             }
+            StackMapFrame::SameLocals1StackItemFrame(SameLocals1StackItemFrame {
+                frame_type,
+                stack,
+            }) => write!(
+                f,
+                "{}   /* same_locals_1_stack_item */
+    stack = [{}]",
+                frame_type, stack[0]
+            ),
+            StackMapFrame::SameLocals1StackItemFrameExtended(
+                SameLocals1StackItemFrameExtended {
+                    frame_type,
+                    offset_delta,
+                    stack,
+                },
+            ) => write!(
+                f,
+                "{}   /* same_locals_1_stack_item_frame_extended */
+    offset_delta = {}
+    stack = [{}]",
+                frame_type, offset_delta, stack[0]
+            ),
+            StackMapFrame::SameFrameExtended(SameFrameExtended {
+                frame_type,
+                offset_delta,
+            }) => write!(
+                f,
+                "{}   /* same_frame_extended */
+    offset_delta = {}",
+                frame_type, offset_delta
+            ),
             StackMapFrame::ChopFrame(ChopFrame {
                 frame_type,
                 offset_delta,
@@ -462,7 +1786,6 @@ impl fmt::Display for StackMapFrame {
                         .join(", ")
                 )
             ),
-            _ => unimplemented!(),
         }
     }
 }
@@ -544,3 +1867,168 @@ pub fn extract_verification_type_info(
     }
     (result, index)
 }
+
+pub fn extract_u2_indexes(
+    inputs: &[u8],
+    original_index: usize,
+    length: usize,
+) -> (Vec<u16>, usize) {
+    let mut index = original_index;
+    let mut result = Vec::with_capacity(length);
+    for _ in 0..length {
+        let (item, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+        result.push(item as u16);
+        index = update_index;
+    }
+    (result, index)
+}
+
+pub fn write_verification_type_info(output: &mut Vec<u8>, type_infos: &[VerificationTypeInfo]) {
+    for type_info in type_infos.iter() {
+        match type_info {
+            VerificationTypeInfo::TopVariableInfo => output.push(0),
+            VerificationTypeInfo::IntegerVariableInfo => output.push(1),
+            VerificationTypeInfo::FloatVariableInfo => output.push(2),
+            VerificationTypeInfo::DoubleVariableInfo => output.push(3),
+            VerificationTypeInfo::LongVariableInfo => output.push(4),
+            VerificationTypeInfo::NullVariableInfo => output.push(5),
+            VerificationTypeInfo::UninitializedThisVariableInfo => output.push(6),
+            VerificationTypeInfo::ObjectVariableInfo(cpool_index) => {
+                output.push(7);
+                write_x_byte_as_usize(output, *cpool_index, 2);
+            }
+            VerificationTypeInfo::UninitializedVariableInfo(offset) => {
+                output.push(8);
+                write_x_byte_as_usize(output, *offset, 2);
+            }
+        }
+    }
+}
+
+pub fn write_u2_indexes(output: &mut Vec<u8>, indexes: &[u16]) {
+    for index in indexes.iter() {
+        write_x_byte_as_usize(output, *index as usize, 2);
+    }
+}
+
+// attribute_length is recomputed from info so that edited attributes stay consistent
+pub fn write_attribute(output: &mut Vec<u8>, attribute_name_index: u16, info: &[u8]) {
+    write_x_byte_as_usize(output, attribute_name_index as usize, 2);
+    write_x_byte_as_usize(output, info.len(), 4);
+    output.extend_from_slice(info);
+}
+
+fn join_indexes(indexes: &[u16]) -> String {
+    indexes
+        .iter()
+        .map(|index| format!("#{}", index))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn create_constant_pool(string_pool: &mut StringPool, names: &[&str]) -> ConstantPool {
+        let mut inputs = vec![];
+        for name in names.iter() {
+            inputs.push(0x01); // utf8
+            inputs.extend_from_slice(&(name.len() as u16).to_be_bytes());
+            inputs.extend_from_slice(name.as_bytes());
+        }
+        let (constant_pool, _) = ConstantPool::new(string_pool, &inputs, 0, names.len() + 1);
+        constant_pool
+    }
+
+    #[test]
+    fn attribute_unknown() {
+        let mut string_pool = StringPool::new();
+        let constant_pool = create_constant_pool(&mut string_pool, &["Custom"]);
+        let inputs = vec![
+            0x00, 0x01, // attribute_name_index
+            0x00, 0x00, 0x00, 0x03, // attribute_length
+            0xaa, 0xbb, 0xcc, // info
+            0xff, // next item
+        ];
+
+        let (attribute, index) = Attribute::new(&mut string_pool, &constant_pool, &inputs, 0);
+
+        assert_eq!(index, 9);
+        match attribute {
+            Attribute::Unknown(item) => {
+                assert_eq!(item.attribute_length, 3);
+                assert_eq!(item.info, vec![0xaa, 0xbb, 0xcc]);
+            }
+            _ => unreachable!("should be Unknown. actual {:?}", attribute),
+        }
+    }
+
+    #[test]
+    fn attribute_record() {
+        let mut string_pool = StringPool::new();
+        let constant_pool = create_constant_pool(&mut string_pool, &["Record", "Signature"]);
+        let inputs = vec![
+            0x00, 0x01, // attribute_name_index
+            0x00, 0x00, 0x00, 0x10, // attribute_length
+            0x00, 0x01, // components_count
+            0x00, 0x0a, // name_index
+            0x00, 0x0b, // descriptor_index
+            0x00, 0x01, // attributes_count
+            0x00, 0x02, // attribute_name_index
+            0x00, 0x00, 0x00, 0x02, // attribute_length
+            0x00, 0x0c, // signature_index
+        ];
+
+        let (attribute, index) = Attribute::new(&mut string_pool, &constant_pool, &inputs, 0);
+
+        assert_eq!(index, inputs.len());
+        match attribute {
+            Attribute::Record(item) => {
+                assert_eq!(item.components_count, 1);
+                let component = &item.components[0];
+                assert_eq!(component.name_index, 0x0a);
+                assert_eq!(component.descriptor_index, 0x0b);
+                assert_eq!(format!("{}", component.attributes[0]), "Signature: #12");
+            }
+            _ => unreachable!("should be Record. actual {:?}", attribute),
+        }
+    }
+
+    #[test]
+    fn attribute_module() {
+        let mut string_pool = StringPool::new();
+        let constant_pool = create_constant_pool(&mut string_pool, &["Module"]);
+        let inputs = vec![
+            0x00, 0x01, // attribute_name_index
+            0x00, 0x00, 0x00, 0x28, // attribute_length
+            0x00, 0x0a, // module_name_index
+            0x00, 0x20, // module_flags
+            0x00, 0x00, // module_version_index
+            0x00, 0x01, // requires_count
+            0x00, 0x0b, 0x80, 0x00, 0x00, 0x00, // requires
+            0x00, 0x01, // exports_count
+            0x00, 0x0c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x0d, // exports
+            0x00, 0x00, // opens_count
+            0x00, 0x01, // uses_count
+            0x00, 0x0e, // uses_index
+            0x00, 0x01, // provides_count
+            0x00, 0x0f, 0x00, 0x02, 0x00, 0x10, 0x00, 0x11, // provides
+        ];
+
+        let (attribute, index) = Attribute::new(&mut string_pool, &constant_pool, &inputs, 0);
+
+        assert_eq!(index, inputs.len());
+        match attribute {
+            Attribute::Module(item) => {
+                assert_eq!(item.module_name_index, 0x0a);
+                assert_eq!(item.requires[0].requires_flags, 0x8000);
+                assert_eq!(item.exports[0].exports_to_index, vec![0x0d]);
+                assert_eq!(item.opens_count, 0);
+                assert_eq!(item.uses_index, vec![0x0e]);
+                assert_eq!(item.provides[0].provides_with_index, vec![0x10, 0x11]);
+            }
+            _ => unreachable!("should be Module. actual {:?}", attribute),
+        }
+    }
+}
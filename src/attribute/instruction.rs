@@ -1,88 +1,167 @@
-use crate::utils::{extract_x_byte_as_usize, extract_x_byte_as_vec};
+use crate::utils::{extract_x_byte_as_usize, extract_x_byte_as_vec, write_x_byte_as_usize};
 use std::fmt;
 
 #[derive(Debug)]
 pub enum Instruction {
-    Aconstnull,                                // 0x01
-    IconstN(i32),                              // 0x02(-1) - 0x08(5)
-    LconstN(usize),                            // 0x09(0) - 0x0a(1)
-    FconstN(f32),                              // 0x0b(0) - 0x0d(1)
-    DconstN(usize),                            // 0x0e(0) - 0x0f(1)
-    Bipush(i32),                               // 0x10
-    Sipush(usize),                             // 0x11
-    Ldc(usize),                                // 0x12
-    Ldc2W(usize, usize),                       // 0x14
-    Iload(usize),                              // 0x15
-    Aload(usize),                              // 0x19
-    IloadN(usize),                             // 0x1a(0) - 0x1d(3)
-    LloadN(usize),                             // 0x1e(0) - 0x21(3)
-    FloadN(usize),                             // 0x22(0) - 0x25(3)
-    DloadN(usize),                             // 0x26(0) - 0x29(3)
-    AloadN(usize),                             // 0x2a(0) - 0x2d(3)
-    Iaload,                                    // 0x2e
-    Laload,                                    // 0x2f
-    Aaload,                                    // 0x32
-    Baload,                                    // 0x33
-    Istore(i32),                               // 0x36
-    Astore(usize),                             // 0x3a
-    IstoreN(i32),                              // 0x3b(0) - 0x3e(3)
-    LstoreN(usize),                            // 0x3f(0) - 0x42(3)
-    FstoreN(usize),                            // 0x43(0) - 0x46(3)
-    DstoreN(usize),                            // 0x47(0) - 0x4a(3)
-    AstoreN(usize),                            // 0x4b(0) - 0x4e(3)
-    Iastore,                                   // 0x4f
-    Lastore,                                   // 0x50
-    Aastore,                                   // 0x53
-    Bastore,                                   // 0x54
-    Pop,                                       // 0x57
-    Dup,                                       // 0x59
-    Iadd,                                      // 0x60
-    Ladd,                                      // 0x61
-    Fadd,                                      // 0x62
-    Isub,                                      // 0x64
-    Lsub,                                      // 0x65
-    Fsub,                                      // 0x66
-    Imul,                                      // 0x68
-    Lmul,                                      // 0x69
-    Fmul,                                      // 0x6a
-    Idiv,                                      // 0x6c
-    Ldiv,                                      // 0x6d
-    Fdiv,                                      // 0x6e
-    Irem,                                      // 0x70
-    Lrem,                                      // 0x71
-    Iinc(usize, usize),                        // 0x84
-    Lcmp,                                      // 0x94
-    Fcmpg,                                     // 0x95
-    Fcmpl,                                     // 0x96
-    Ifeq(usize, usize),                        // 0x99
-    Ifne(usize, usize),                        // 0x9a
-    Iflt(usize, usize),                        // 0x9b
-    Ifge(usize, usize),                        // 0x9c
-    Ifgt(usize, usize),                        // 0x9d
-    Ifle(usize, usize),                        // 0x9e
-    Ificmpeq(usize, usize),                    // 0x9f
-    Ificmpne(usize, usize),                    // 0xa0
-    Ificmplt(usize, usize),                    // 0xa1
-    Ificmpge(usize, usize),                    // 0xa2
-    Ificmpgt(usize, usize),                    // 0xa3
-    Ificmple(usize, usize),                    // 0xa4
-    Goto(usize),                               // 0xa7
-    Lookupswitch(Vec<(Option<usize>, usize)>), // 0xab
-    Ireturn,                                   // 0xac
-    Areturn,                                   // 0xb0
-    Return,                                    // 0xb1
-    Getstatic(usize),                          // 0xb2
-    Putstatic(usize),                          // 0xb3
-    Getfield(usize),                           // 0xb4
-    Putfield(usize),                           // 0xb5
-    Invokevirtual(usize),                      // 0xb6
-    Invokespecial(usize),                      // 0xb7
-    Invokestatic(usize),                       // 0xb8
-    New(usize),                                // 0xbb
-    Newarray(usize),                           // 0xbc
-    Anewarray(usize),                          // 0xbd
-    Multianewarray(usize, usize),              // 0xc5
-    Noope,                                     // custom command for Ificmple etc.
+    Aconstnull,                               // 0x01
+    IconstN(i32),                             // 0x02(-1) - 0x08(5)
+    LconstN(usize),                           // 0x09(0) - 0x0a(1)
+    FconstN(f32),                             // 0x0b(0) - 0x0d(1)
+    DconstN(usize),                           // 0x0e(0) - 0x0f(1)
+    Bipush(i32),                              // 0x10
+    Sipush(i32),                              // 0x11
+    Ldc(usize),                               // 0x12
+    LdcW(usize),                              // 0x13
+    Ldc2W(usize, usize),                      // 0x14
+    Iload(usize),                             // 0x15
+    Lload(usize),                             // 0x16
+    Fload(usize),                             // 0x17
+    Dload(usize),                             // 0x18
+    Aload(usize),                             // 0x19
+    IloadN(usize),                            // 0x1a(0) - 0x1d(3)
+    LloadN(usize),                            // 0x1e(0) - 0x21(3)
+    FloadN(usize),                            // 0x22(0) - 0x25(3)
+    DloadN(usize),                            // 0x26(0) - 0x29(3)
+    AloadN(usize),                            // 0x2a(0) - 0x2d(3)
+    Iaload,                                   // 0x2e
+    Laload,                                   // 0x2f
+    Faload,                                   // 0x30
+    Daload,                                   // 0x31
+    Aaload,                                   // 0x32
+    Baload,                                   // 0x33
+    Caload,                                   // 0x34
+    Saload,                                   // 0x35
+    Istore(i32),                              // 0x36
+    Lstore(usize),                            // 0x37
+    Fstore(usize),                            // 0x38
+    Dstore(usize),                            // 0x39
+    Astore(usize),                            // 0x3a
+    IstoreN(i32),                             // 0x3b(0) - 0x3e(3)
+    LstoreN(usize),                           // 0x3f(0) - 0x42(3)
+    FstoreN(usize),                           // 0x43(0) - 0x46(3)
+    DstoreN(usize),                           // 0x47(0) - 0x4a(3)
+    AstoreN(usize),                           // 0x4b(0) - 0x4e(3)
+    Iastore,                                  // 0x4f
+    Lastore,                                  // 0x50
+    Fastore,                                  // 0x51
+    Dastore,                                  // 0x52
+    Aastore,                                  // 0x53
+    Bastore,                                  // 0x54
+    Castore,                                  // 0x55
+    Sastore,                                  // 0x56
+    Pop,                                      // 0x57
+    Pop2,                                     // 0x58
+    Dup,                                      // 0x59
+    DupX1,                                    // 0x5a
+    DupX2,                                    // 0x5b
+    Dup2,                                     // 0x5c
+    Dup2X1,                                   // 0x5d
+    Dup2X2,                                   // 0x5e
+    Swap,                                     // 0x5f
+    Iadd,                                     // 0x60
+    Ladd,                                     // 0x61
+    Fadd,                                     // 0x62
+    Dadd,                                     // 0x63
+    Isub,                                     // 0x64
+    Lsub,                                     // 0x65
+    Fsub,                                     // 0x66
+    Dsub,                                     // 0x67
+    Imul,                                     // 0x68
+    Lmul,                                     // 0x69
+    Fmul,                                     // 0x6a
+    Dmul,                                     // 0x6b
+    Idiv,                                     // 0x6c
+    Ldiv,                                     // 0x6d
+    Fdiv,                                     // 0x6e
+    Ddiv,                                     // 0x6f
+    Irem,                                     // 0x70
+    Lrem,                                     // 0x71
+    Frem,                                     // 0x72
+    Drem,                                     // 0x73
+    Ineg,                                     // 0x74
+    Lneg,                                     // 0x75
+    Fneg,                                     // 0x76
+    Dneg,                                     // 0x77
+    Ishl,                                     // 0x78
+    Lshl,                                     // 0x79
+    Ishr,                                     // 0x7a
+    Lshr,                                     // 0x7b
+    Iushr,                                    // 0x7c
+    Lushr,                                    // 0x7d
+    Iand,                                     // 0x7e
+    Land,                                     // 0x7f
+    Ior,                                      // 0x80
+    Lor,                                      // 0x81
+    Ixor,                                     // 0x82
+    Lxor,                                     // 0x83
+    Iinc(usize, i32),                         // 0x84
+    I2l,                                      // 0x85
+    I2f,                                      // 0x86
+    I2d,                                      // 0x87
+    L2i,                                      // 0x88
+    L2f,                                      // 0x89
+    L2d,                                      // 0x8a
+    F2i,                                      // 0x8b
+    F2l,                                      // 0x8c
+    F2d,                                      // 0x8d
+    D2i,                                      // 0x8e
+    D2l,                                      // 0x8f
+    D2f,                                      // 0x90
+    I2b,                                      // 0x91
+    I2c,                                      // 0x92
+    I2s,                                      // 0x93
+    Lcmp,                                     // 0x94
+    Fcmpg,                                    // 0x95
+    Fcmpl,                                    // 0x96
+    Dcmpl,                                    // 0x97
+    Dcmpg,                                    // 0x98
+    Ifeq(usize, usize),                       // 0x99
+    Ifne(usize, usize),                       // 0x9a
+    Iflt(usize, usize),                       // 0x9b
+    Ifge(usize, usize),                       // 0x9c
+    Ifgt(usize, usize),                       // 0x9d
+    Ifle(usize, usize),                       // 0x9e
+    Ificmpeq(usize, usize),                   // 0x9f
+    Ificmpne(usize, usize),                   // 0xa0
+    Ificmplt(usize, usize),                   // 0xa1
+    Ificmpge(usize, usize),                   // 0xa2
+    Ificmpgt(usize, usize),                   // 0xa3
+    Ificmple(usize, usize),                   // 0xa4
+    Ifacmpeq(usize, usize),                   // 0xa5
+    Ifacmpne(usize, usize),                   // 0xa6
+    Goto(usize),                              // 0xa7
+    Jsr(usize),                               // 0xa8
+    Ret(usize),                               // 0xa9
+    Tableswitch(i32, i32, usize, Vec<usize>), // 0xaa
+    Lookupswitch(usize, Vec<(i32, usize)>),   // 0xab
+    Ireturn,                                  // 0xac
+    Lreturn,                                  // 0xad
+    Freturn,                                  // 0xae
+    Dreturn,                                  // 0xaf
+    Areturn,                                  // 0xb0
+    Return,                                   // 0xb1
+    Getstatic(usize),                         // 0xb2
+    Putstatic(usize),                         // 0xb3
+    Getfield(usize),                          // 0xb4
+    Putfield(usize),                          // 0xb5
+    Invokevirtual(usize),                     // 0xb6
+    Invokespecial(usize),                     // 0xb7
+    Invokestatic(usize),                      // 0xb8
+    Invokeinterface(usize, usize),            // 0xb9
+    New(usize),                               // 0xbb
+    Newarray(usize),                          // 0xbc
+    Anewarray(usize),                         // 0xbd
+    Arraylength,                              // 0xbe
+    Athrow,                                   // 0xbf
+    Checkcast(usize),                         // 0xc0
+    Instanceof(usize),                        // 0xc1
+    Wide(Box<Instruction>),                   // 0xc4
+    Multianewarray(usize, usize),             // 0xc5
+    Ifnull(usize, usize),                     // 0xc6
+    Ifnonnull(usize, usize),                  // 0xc7
+    GotoW(usize),                             // 0xc8
+    JsrW(usize),                              // 0xc9
+    Noope,                                    // custom command for Ificmple etc.
 }
 
 impl fmt::Display for Instruction {
@@ -96,8 +175,12 @@ impl fmt::Display for Instruction {
             Instruction::Bipush(val) => write!(f, "bipush         {}", val),
             Instruction::Sipush(val) => write!(f, "sipush         {}", val),
             Instruction::Ldc(val) => write!(f, "ldc             #{}", val),
+            Instruction::LdcW(val) => write!(f, "ldc_w           #{}", val),
             Instruction::Ldc2W(a, b) => write!(f, "ldc2_w         #{},{}", a, b),
             Instruction::Iload(val) => write!(f, "iload            #{}", val),
+            Instruction::Lload(val) => write!(f, "lload            #{}", val),
+            Instruction::Fload(val) => write!(f, "fload            #{}", val),
+            Instruction::Dload(val) => write!(f, "dload            #{}", val),
             Instruction::Aload(val) => write!(f, "aload            #{}", val),
             Instruction::IloadN(val) => write!(f, "iload_{}", val),
             Instruction::LloadN(val) => write!(f, "lload_{}", val),
@@ -106,39 +189,96 @@ impl fmt::Display for Instruction {
             Instruction::AloadN(val) => write!(f, "aload_{}", val),
             Instruction::Iaload => write!(f, "iaload"),
             Instruction::Laload => write!(f, "laload"),
+            Instruction::Faload => write!(f, "faload"),
+            Instruction::Daload => write!(f, "daload"),
             Instruction::Aaload => write!(f, "aaload"),
             Instruction::Baload => write!(f, "baload"),
+            Instruction::Caload => write!(f, "caload"),
+            Instruction::Saload => write!(f, "saload"),
             Instruction::Istore(val) => write!(f, "istore            #{}", val),
+            Instruction::Lstore(val) => write!(f, "lstore            #{}", val),
+            Instruction::Fstore(val) => write!(f, "fstore            #{}", val),
+            Instruction::Dstore(val) => write!(f, "dstore            #{}", val),
             Instruction::Astore(val) => write!(f, "astore            #{}", val),
             Instruction::Aastore => write!(f, "aastore"),
             Instruction::Bastore => write!(f, "bastore"),
+            Instruction::Castore => write!(f, "castore"),
+            Instruction::Sastore => write!(f, "sastore"),
             Instruction::IstoreN(val) => write!(f, "istore_{}", val),
             Instruction::LstoreN(val) => write!(f, "lstore_{}", val),
             Instruction::FstoreN(val) => write!(f, "fstore_{}", val),
             Instruction::DstoreN(val) => write!(f, "dstore_{}", val),
             Instruction::Iastore => write!(f, "iastore"),
             Instruction::Lastore => write!(f, "lastore"),
+            Instruction::Fastore => write!(f, "fastore"),
+            Instruction::Dastore => write!(f, "dastore"),
             Instruction::AstoreN(val) => write!(f, "astore_{}", val),
             Instruction::Pop => write!(f, "pop"),
+            Instruction::Pop2 => write!(f, "pop2"),
             Instruction::Dup => write!(f, "dup"),
+            Instruction::DupX1 => write!(f, "dup_x1"),
+            Instruction::DupX2 => write!(f, "dup_x2"),
+            Instruction::Dup2 => write!(f, "dup2"),
+            Instruction::Dup2X1 => write!(f, "dup2_x1"),
+            Instruction::Dup2X2 => write!(f, "dup2_x2"),
+            Instruction::Swap => write!(f, "swap"),
             Instruction::Iadd => write!(f, "iadd"),
             Instruction::Ladd => write!(f, "ladd"),
             Instruction::Fadd => write!(f, "fadd"),
+            Instruction::Dadd => write!(f, "dadd"),
             Instruction::Isub => write!(f, "isub"),
             Instruction::Lsub => write!(f, "lsub"),
             Instruction::Fsub => write!(f, "fsub"),
+            Instruction::Dsub => write!(f, "dsub"),
             Instruction::Imul => write!(f, "imul"),
             Instruction::Lmul => write!(f, "lmul"),
             Instruction::Fmul => write!(f, "fmul"),
+            Instruction::Dmul => write!(f, "dmul"),
             Instruction::Idiv => write!(f, "idiv"),
             Instruction::Ldiv => write!(f, "ldiv"),
             Instruction::Fdiv => write!(f, "fdiv"),
+            Instruction::Ddiv => write!(f, "ddiv"),
             Instruction::Irem => write!(f, "irem"),
             Instruction::Lrem => write!(f, "lrem"),
+            Instruction::Frem => write!(f, "frem"),
+            Instruction::Drem => write!(f, "drem"),
+            Instruction::Ineg => write!(f, "ineg"),
+            Instruction::Lneg => write!(f, "lneg"),
+            Instruction::Fneg => write!(f, "fneg"),
+            Instruction::Dneg => write!(f, "dneg"),
+            Instruction::Ishl => write!(f, "ishl"),
+            Instruction::Lshl => write!(f, "lshl"),
+            Instruction::Ishr => write!(f, "ishr"),
+            Instruction::Lshr => write!(f, "lshr"),
+            Instruction::Iushr => write!(f, "iushr"),
+            Instruction::Lushr => write!(f, "lushr"),
+            Instruction::Iand => write!(f, "iand"),
+            Instruction::Land => write!(f, "land"),
+            Instruction::Ior => write!(f, "ior"),
+            Instruction::Lor => write!(f, "lor"),
+            Instruction::Ixor => write!(f, "ixor"),
+            Instruction::Lxor => write!(f, "lxor"),
             Instruction::Iinc(a, b) => write!(f, "iinc        {}, {}", a, b),
+            Instruction::I2l => write!(f, "i2l"),
+            Instruction::I2f => write!(f, "i2f"),
+            Instruction::I2d => write!(f, "i2d"),
+            Instruction::L2i => write!(f, "l2i"),
+            Instruction::L2f => write!(f, "l2f"),
+            Instruction::L2d => write!(f, "l2d"),
+            Instruction::F2i => write!(f, "f2i"),
+            Instruction::F2l => write!(f, "f2l"),
+            Instruction::F2d => write!(f, "f2d"),
+            Instruction::D2i => write!(f, "d2i"),
+            Instruction::D2l => write!(f, "d2l"),
+            Instruction::D2f => write!(f, "d2f"),
+            Instruction::I2b => write!(f, "i2b"),
+            Instruction::I2c => write!(f, "i2c"),
+            Instruction::I2s => write!(f, "i2s"),
             Instruction::Lcmp => write!(f, "lcmp"),
             Instruction::Fcmpg => write!(f, "fcmpg"),
             Instruction::Fcmpl => write!(f, "fcmpl"),
+            Instruction::Dcmpl => write!(f, "dcmpl"),
+            Instruction::Dcmpg => write!(f, "dcmpg"),
             Instruction::Ifeq(a, b) => write!(f, "if_eq       {}, {}", a, b),
             Instruction::Ifne(a, b) => write!(f, "if_ne       {}, {}", a, b),
             Instruction::Iflt(a, b) => write!(f, "if_lt       {}, {}", a, b),
@@ -151,21 +291,49 @@ impl fmt::Display for Instruction {
             Instruction::Ificmpge(a, b) => write!(f, "if_icmpge   {}, {}", a, b),
             Instruction::Ificmpgt(a, b) => write!(f, "if_icmpgt   {}, {}", a, b),
             Instruction::Ificmple(a, b) => write!(f, "if_icmple   {}, {}", a, b),
+            Instruction::Ifacmpeq(a, b) => write!(f, "if_acmpeq   {}, {}", a, b),
+            Instruction::Ifacmpne(a, b) => write!(f, "if_acmpne   {}, {}", a, b),
+            Instruction::Ifnull(a, b) => write!(f, "ifnull      {}, {}", a, b),
+            Instruction::Ifnonnull(a, b) => write!(f, "ifnonnull   {}, {}", a, b),
             Instruction::Goto(val) => write!(f, "goto          {}", val),
+            Instruction::Jsr(val) => write!(f, "jsr           {}", val),
+            Instruction::Ret(val) => write!(f, "ret           #{}", val),
+            Instruction::GotoW(val) => write!(f, "goto_w        {}", val),
+            Instruction::JsrW(val) => write!(f, "jsr_w         {}", val),
+            Instruction::Wide(instruction) => write!(f, "wide {}", instruction),
             Instruction::Ireturn => write!(f, "ireturn"),
-            Instruction::Lookupswitch(vals) => {
-                let vals_length = vals.len();
-                let mut output_strings = Vec::with_capacity(vals_length);
-                for (key, val) in &vals[1..vals_length] {
-                    output_strings.push(format!("       {}: {}", key.unwrap(), val));
+            Instruction::Lreturn => write!(f, "lreturn"),
+            Instruction::Freturn => write!(f, "freturn"),
+            Instruction::Dreturn => write!(f, "dreturn"),
+            Instruction::Tableswitch(low, high, default, jump_pointers) => {
+                let mut output_strings = Vec::with_capacity(jump_pointers.len() + 1);
+                for (key, val) in (*low..=*high).zip(jump_pointers.iter()) {
+                    output_strings.push(format!("       {}: {}", key, val));
                 }
-                output_strings.push(format!("       default: {}", vals.last().unwrap().1));
+                output_strings.push(format!("       default: {}", default));
+                write!(
+                    f,
+                    "tableswitch {{ // {} to {}
+{}
+}}",
+                    low,
+                    high,
+                    output_strings.join("\n")
+                )
+            }
+            Instruction::Lookupswitch(default, pairs) => {
+                let pairs_length = pairs.len();
+                let mut output_strings = Vec::with_capacity(pairs_length + 1);
+                for (key, val) in pairs.iter() {
+                    output_strings.push(format!("       {}: {}", key, val));
+                }
+                output_strings.push(format!("       default: {}", default));
                 write!(
                     f,
                     "lookupswitch {{ // {}
 {}
 }}",
-                    vals_length - 1,
+                    pairs_length,
                     output_strings.join("\n")
                 )
             }
@@ -178,9 +346,16 @@ impl fmt::Display for Instruction {
             Instruction::Invokevirtual(val) => write!(f, "invokevirtual   #{}", val),
             Instruction::Invokespecial(val) => write!(f, "invokespecial   #{}", val),
             Instruction::Invokestatic(val) => write!(f, "invokestatic   #{}", val),
+            Instruction::Invokeinterface(index, count) => {
+                write!(f, "invokeinterface #{},  {}", index, count)
+            }
             Instruction::New(val) => write!(f, "new            #{}", val),
             Instruction::Newarray(val) => write!(f, "newarray       #{}", val),
             Instruction::Anewarray(val) => write!(f, "anewarray      #{}", val),
+            Instruction::Arraylength => write!(f, "arraylength"),
+            Instruction::Athrow => write!(f, "athrow"),
+            Instruction::Checkcast(val) => write!(f, "checkcast      #{}", val),
+            Instruction::Instanceof(val) => write!(f, "instanceof     #{}", val),
             Instruction::Multianewarray(index, dimensions) => {
                 write!(f, "multianewarray    #{} {}", index, dimensions)
             }
@@ -227,19 +402,14 @@ impl Instruction {
             // bipush
             0x10 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
-                let val = if val > 0x79 {
-                    -1 * ((val ^ 0xff) + 1) as i32
-                } else {
-                    val as i32
-                };
-                codes.push(Instruction::Bipush(val));
+                codes.push(Instruction::Bipush(val as i8 as i32));
                 codes.push(Instruction::Noope);
                 (index, 2)
             }
             // sipush
             0x11 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
-                codes.push(Instruction::Sipush(val));
+                codes.push(Instruction::Sipush(val as i16 as i32));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
                 (index, 3)
@@ -251,6 +421,14 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 2)
             }
+            // ldc_w
+            0x13 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                codes.push(Instruction::LdcW(val));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
             // ldc2_w
             0x14 => {
                 let (val, index) = extract_x_byte_as_vec(inputs, index, 2);
@@ -266,6 +444,27 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 2)
             }
+            // lload
+            0x16 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Lload(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
+            // fload
+            0x17 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Fload(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
+            // dload
+            0x18 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Dload(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
             // aload
             0x19 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
@@ -301,6 +500,14 @@ impl Instruction {
             0x2f => {
                 simple_instruct!(Instruction::Laload);
             }
+            // faload
+            0x30 => {
+                simple_instruct!(Instruction::Faload);
+            }
+            // daload
+            0x31 => {
+                simple_instruct!(Instruction::Daload);
+            }
             // iaload
             0x32 => {
                 simple_instruct!(Instruction::Aaload);
@@ -309,6 +516,14 @@ impl Instruction {
             0x33 => {
                 simple_instruct!(Instruction::Baload);
             }
+            // caload
+            0x34 => {
+                simple_instruct!(Instruction::Caload);
+            }
+            // saload
+            0x35 => {
+                simple_instruct!(Instruction::Saload);
+            }
             // istore
             0x36 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
@@ -316,6 +531,27 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 2)
             }
+            // lstore
+            0x37 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Lstore(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
+            // fstore
+            0x38 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Fstore(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
+            // dstore
+            0x39 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Dstore(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
             // astore
             0x3a => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
@@ -351,6 +587,14 @@ impl Instruction {
             0x50 => {
                 simple_instruct!(Instruction::Lastore);
             }
+            // fastore
+            0x51 => {
+                simple_instruct!(Instruction::Fastore);
+            }
+            // dastore
+            0x52 => {
+                simple_instruct!(Instruction::Dastore);
+            }
             // aastore
             0x53 => {
                 simple_instruct!(Instruction::Aastore);
@@ -359,14 +603,50 @@ impl Instruction {
             0x54 => {
                 simple_instruct!(Instruction::Bastore);
             }
+            // castore
+            0x55 => {
+                simple_instruct!(Instruction::Castore);
+            }
+            // sastore
+            0x56 => {
+                simple_instruct!(Instruction::Sastore);
+            }
             // pop
             0x57 => {
                 simple_instruct!(Instruction::Pop);
             }
+            // pop2
+            0x58 => {
+                simple_instruct!(Instruction::Pop2);
+            }
             // dup
             0x59 => {
                 simple_instruct!(Instruction::Dup);
             }
+            // dup_x1
+            0x5a => {
+                simple_instruct!(Instruction::DupX1);
+            }
+            // dup_x2
+            0x5b => {
+                simple_instruct!(Instruction::DupX2);
+            }
+            // dup2
+            0x5c => {
+                simple_instruct!(Instruction::Dup2);
+            }
+            // dup2_x1
+            0x5d => {
+                simple_instruct!(Instruction::Dup2X1);
+            }
+            // dup2_x2
+            0x5e => {
+                simple_instruct!(Instruction::Dup2X2);
+            }
+            // swap
+            0x5f => {
+                simple_instruct!(Instruction::Swap);
+            }
             // iadd
             0x60 => {
                 simple_instruct!(Instruction::Iadd);
@@ -379,6 +659,10 @@ impl Instruction {
             0x62 => {
                 simple_instruct!(Instruction::Fadd);
             }
+            // dadd
+            0x63 => {
+                simple_instruct!(Instruction::Dadd);
+            }
             // isub
             0x64 => {
                 simple_instruct!(Instruction::Isub);
@@ -391,6 +675,10 @@ impl Instruction {
             0x66 => {
                 simple_instruct!(Instruction::Fsub);
             }
+            // dsub
+            0x67 => {
+                simple_instruct!(Instruction::Dsub);
+            }
             // imul
             0x68 => {
                 simple_instruct!(Instruction::Imul);
@@ -403,6 +691,10 @@ impl Instruction {
             0x6a => {
                 simple_instruct!(Instruction::Fmul);
             }
+            // dmul
+            0x6b => {
+                simple_instruct!(Instruction::Dmul);
+            }
             // idiv
             0x6c => {
                 simple_instruct!(Instruction::Idiv);
@@ -415,6 +707,10 @@ impl Instruction {
             0x6e => {
                 simple_instruct!(Instruction::Fdiv);
             }
+            // ddiv
+            0x6f => {
+                simple_instruct!(Instruction::Ddiv);
+            }
             // irem
             0x70 => {
                 simple_instruct!(Instruction::Irem);
@@ -423,14 +719,146 @@ impl Instruction {
             0x71 => {
                 simple_instruct!(Instruction::Lrem);
             }
+            // frem
+            0x72 => {
+                simple_instruct!(Instruction::Frem);
+            }
+            // drem
+            0x73 => {
+                simple_instruct!(Instruction::Drem);
+            }
+            // ineg
+            0x74 => {
+                simple_instruct!(Instruction::Ineg);
+            }
+            // lneg
+            0x75 => {
+                simple_instruct!(Instruction::Lneg);
+            }
+            // fneg
+            0x76 => {
+                simple_instruct!(Instruction::Fneg);
+            }
+            // dneg
+            0x77 => {
+                simple_instruct!(Instruction::Dneg);
+            }
+            // ishl
+            0x78 => {
+                simple_instruct!(Instruction::Ishl);
+            }
+            // lshl
+            0x79 => {
+                simple_instruct!(Instruction::Lshl);
+            }
+            // ishr
+            0x7a => {
+                simple_instruct!(Instruction::Ishr);
+            }
+            // lshr
+            0x7b => {
+                simple_instruct!(Instruction::Lshr);
+            }
+            // iushr
+            0x7c => {
+                simple_instruct!(Instruction::Iushr);
+            }
+            // lushr
+            0x7d => {
+                simple_instruct!(Instruction::Lushr);
+            }
+            // iand
+            0x7e => {
+                simple_instruct!(Instruction::Iand);
+            }
+            // land
+            0x7f => {
+                simple_instruct!(Instruction::Land);
+            }
+            // ior
+            0x80 => {
+                simple_instruct!(Instruction::Ior);
+            }
+            // lor
+            0x81 => {
+                simple_instruct!(Instruction::Lor);
+            }
+            // ixor
+            0x82 => {
+                simple_instruct!(Instruction::Ixor);
+            }
+            // lxor
+            0x83 => {
+                simple_instruct!(Instruction::Lxor);
+            }
             // iinc
             0x84 => {
                 let (val, index) = extract_x_byte_as_vec(inputs, index, 2);
-                codes.push(Instruction::Iinc(val[0] as usize, val[1] as usize));
+                codes.push(Instruction::Iinc(val[0] as usize, val[1] as i8 as i32));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
                 (index, 3)
             }
+            // i2l
+            0x85 => {
+                simple_instruct!(Instruction::I2l);
+            }
+            // i2f
+            0x86 => {
+                simple_instruct!(Instruction::I2f);
+            }
+            // i2d
+            0x87 => {
+                simple_instruct!(Instruction::I2d);
+            }
+            // l2i
+            0x88 => {
+                simple_instruct!(Instruction::L2i);
+            }
+            // l2f
+            0x89 => {
+                simple_instruct!(Instruction::L2f);
+            }
+            // l2d
+            0x8a => {
+                simple_instruct!(Instruction::L2d);
+            }
+            // f2i
+            0x8b => {
+                simple_instruct!(Instruction::F2i);
+            }
+            // f2l
+            0x8c => {
+                simple_instruct!(Instruction::F2l);
+            }
+            // f2d
+            0x8d => {
+                simple_instruct!(Instruction::F2d);
+            }
+            // d2i
+            0x8e => {
+                simple_instruct!(Instruction::D2i);
+            }
+            // d2l
+            0x8f => {
+                simple_instruct!(Instruction::D2l);
+            }
+            // d2f
+            0x90 => {
+                simple_instruct!(Instruction::D2f);
+            }
+            // i2b
+            0x91 => {
+                simple_instruct!(Instruction::I2b);
+            }
+            // i2c
+            0x92 => {
+                simple_instruct!(Instruction::I2c);
+            }
+            // i2s
+            0x93 => {
+                simple_instruct!(Instruction::I2s);
+            }
             // lcmp
             0x94 => {
                 simple_instruct!(Instruction::Lcmp);
@@ -443,13 +871,21 @@ impl Instruction {
             0x96 => {
                 simple_instruct!(Instruction::Fcmpl);
             }
+            // dcmpl
+            0x97 => {
+                simple_instruct!(Instruction::Dcmpl);
+            }
+            // dcmpg
+            0x98 => {
+                simple_instruct!(Instruction::Dcmpg);
+            }
             // ifeq
             0x99 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ifeq(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -460,8 +896,8 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ifne(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -472,8 +908,8 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Iflt(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -484,8 +920,8 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ifge(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -496,8 +932,8 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ifgt(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -508,8 +944,8 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ifle(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -520,8 +956,8 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ificmpeq(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -532,8 +968,8 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ificmpne(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -544,8 +980,8 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ificmplt(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -556,8 +992,8 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ificmpge(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -568,8 +1004,8 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ificmpgt(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -580,8 +1016,32 @@ impl Instruction {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
                 codes.push(Instruction::Ificmple(
-                    (val + code_length - 1) & 0xffff,
-                    code_length + 2,
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
+                ));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // if_acmpeq
+            0xa5 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let code_length = codes.len();
+                codes.push(Instruction::Ifacmpeq(
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
+                ));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // if_acmpne
+            0xa6 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let code_length = codes.len();
+                codes.push(Instruction::Ifacmpne(
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
                 ));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
@@ -591,39 +1051,99 @@ impl Instruction {
             0xa7 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
                 let code_length = codes.len();
-                codes.push(Instruction::Goto((val + code_length - 1) & 0xFFFF));
+                codes.push(Instruction::Goto((val + code_length) & 0xFFFF));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // jsr
+            0xa8 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let code_length = codes.len();
+                codes.push(Instruction::Jsr((val + code_length) & 0xFFFF));
                 codes.push(Instruction::Noope);
                 codes.push(Instruction::Noope);
                 (index, 3)
             }
+            // ret
+            0xa9 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Ret(val));
+                codes.push(Instruction::Noope);
+                (index, 2)
+            }
+            // tableswitch
+            0xaa => {
+                let code_length = codes.len();
+                let index = index + switch_padding(code_length);
+                let (default, index) = extract_x_byte_as_usize(inputs, index, 4);
+                let (low, index) = extract_x_byte_as_usize(inputs, index, 4);
+                let (high, mut index) = extract_x_byte_as_usize(inputs, index, 4);
+                let (low, high) = (low as i32, high as i32);
+
+                let jump_length = (high - low + 1) as usize;
+                let mut jump_pointers = Vec::with_capacity(jump_length);
+                for _ in 0..jump_length {
+                    let (offset, update_index) = extract_x_byte_as_usize(inputs, index, 4);
+                    jump_pointers.push(wide_jump_pointer(code_length, offset));
+                    index = update_index;
+                }
+                codes.push(Instruction::Tableswitch(
+                    low,
+                    high,
+                    wide_jump_pointer(code_length, default),
+                    jump_pointers,
+                ));
+
+                // opecode + padding + default, low, high + jump offsets
+                let consume_length = 1 + switch_padding(code_length) + 4 * 3 + jump_length * 4;
+                for _ in 1..consume_length {
+                    codes.push(Instruction::Noope)
+                }
+                (index, consume_length)
+            }
             // lookupswitch
             0xab => {
-                let (offset, index) = extract_x_byte_as_usize(inputs, index, 4);
-                // default_value can be used for branch_length
-                let (default_value, mut index) = extract_x_byte_as_usize(inputs, index, 4);
-                // default + branch_length
-                let mut switch_values = Vec::with_capacity(1 + default_value);
-                switch_values.push((None, offset + default_value));
+                let code_length = codes.len();
+                let index = index + switch_padding(code_length);
+                let (default, index) = extract_x_byte_as_usize(inputs, index, 4);
+                let (npairs, mut index) = extract_x_byte_as_usize(inputs, index, 4);
 
-                for _ in 0..default_value {
+                let mut pairs = Vec::with_capacity(npairs);
+                for _ in 0..npairs {
                     let (key, update_index) = extract_x_byte_as_usize(inputs, index, 4);
-                    let (val, update_index) = extract_x_byte_as_usize(inputs, update_index, 4);
-                    switch_values.push((Some(key), val + default_value));
+                    let (offset, update_index) = extract_x_byte_as_usize(inputs, update_index, 4);
+                    pairs.push((key as i32, wide_jump_pointer(code_length, offset)));
                     index = update_index
                 }
-                codes.push(Instruction::Lookupswitch(switch_values));
+                codes.push(Instruction::Lookupswitch(
+                    wide_jump_pointer(code_length, default),
+                    pairs,
+                ));
 
-                let set_length = default_value + 1;
-                let switch_instructions_len = set_length * 4 * 2;
-                for _ in 0..switch_instructions_len {
+                // opecode + padding + default, npairs + match-offset pairs
+                let consume_length = 1 + switch_padding(code_length) + 4 * 2 + npairs * 4 * 2;
+                for _ in 1..consume_length {
                     codes.push(Instruction::Noope)
                 }
-                (index, switch_instructions_len + 1)
+                (index, consume_length)
             }
             // ireturn
             0xac => {
                 simple_instruct!(Instruction::Ireturn);
             }
+            // lreturn
+            0xad => {
+                simple_instruct!(Instruction::Lreturn);
+            }
+            // freturn
+            0xae => {
+                simple_instruct!(Instruction::Freturn);
+            }
+            // dreturn
+            0xaf => {
+                simple_instruct!(Instruction::Dreturn);
+            }
             // areturn
             0xb0 => {
                 simple_instruct!(Instruction::Areturn);
@@ -688,6 +1208,19 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 3)
             }
+            // invokeinterface
+            0xb9 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let (count, index) = extract_x_byte_as_usize(inputs, index, 1);
+                // the last byte is always 0
+                let (_, index) = extract_x_byte_as_usize(inputs, index, 1);
+                codes.push(Instruction::Invokeinterface(val, count));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 5)
+            }
             // new
             0xbb => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
@@ -711,6 +1244,59 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 3)
             }
+            // arraylength
+            0xbe => {
+                simple_instruct!(Instruction::Arraylength);
+            }
+            // athrow
+            0xbf => {
+                simple_instruct!(Instruction::Athrow);
+            }
+            // checkcast
+            0xc0 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                codes.push(Instruction::Checkcast(val));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // instanceof
+            0xc1 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                codes.push(Instruction::Instanceof(val));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // wide
+            0xc4 => {
+                let (opcode, index) = extract_x_byte_as_usize(inputs, index, 1);
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let (instruction, index, consumed) = match opcode {
+                    0x15 => (Instruction::Iload(val), index, 4),
+                    0x16 => (Instruction::Lload(val), index, 4),
+                    0x17 => (Instruction::Fload(val), index, 4),
+                    0x18 => (Instruction::Dload(val), index, 4),
+                    0x19 => (Instruction::Aload(val), index, 4),
+                    0x36 => (Instruction::Istore(val as i32), index, 4),
+                    0x37 => (Instruction::Lstore(val), index, 4),
+                    0x38 => (Instruction::Fstore(val), index, 4),
+                    0x39 => (Instruction::Dstore(val), index, 4),
+                    0x3a => (Instruction::Astore(val), index, 4),
+                    0xa9 => (Instruction::Ret(val), index, 4),
+                    // iinc has a signed 16bit constant
+                    0x84 => {
+                        let (constant, index) = extract_x_byte_as_usize(inputs, index, 2);
+                        (Instruction::Iinc(val, constant as i16 as i32), index, 6)
+                    }
+                    _ => unreachable!("wide cannot modify opcode: {:x}", opcode),
+                };
+                codes.push(Instruction::Wide(Box::new(instruction)));
+                for _ in 1..consumed {
+                    codes.push(Instruction::Noope);
+                }
+                (index, consumed)
+            }
             // multianewarray
             0xc5 => {
                 let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
@@ -721,31 +1307,96 @@ impl Instruction {
                 codes.push(Instruction::Noope);
                 (index, 4)
             }
+            // ifnull
+            0xc6 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let code_length = codes.len();
+                codes.push(Instruction::Ifnull(
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
+                ));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // ifnonnull
+            0xc7 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 2);
+                let code_length = codes.len();
+                codes.push(Instruction::Ifnonnull(
+                    (val + code_length) & 0xffff,
+                    code_length + 3,
+                ));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 3)
+            }
+            // goto_w
+            0xc8 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 4);
+                let code_length = codes.len();
+                codes.push(Instruction::GotoW(wide_jump_pointer(code_length, val)));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 5)
+            }
+            // jsr_w
+            0xc9 => {
+                let (val, index) = extract_x_byte_as_usize(inputs, index, 4);
+                let code_length = codes.len();
+                codes.push(Instruction::JsrW(wide_jump_pointer(code_length, val)));
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                codes.push(Instruction::Noope);
+                (index, 5)
+            }
             _ => unimplemented!("tag: {:x}", tag),
         }
     }
 
     pub fn counsume_index(&self) -> usize {
         match self {
-            Instruction::Lookupswitch(vals) => vals.len() * 4,
+            Instruction::Wide(instruction) => match **instruction {
+                Instruction::Iinc(_, _) => 5,
+                _ => 3,
+            },
+            Instruction::GotoW(_) | Instruction::JsrW(_) | Instruction::Invokeinterface(_, _) => 4,
             Instruction::Multianewarray(_, _) => 3,
             Instruction::Ificmple(_, _)
+            | Instruction::Ifacmpeq(_, _)
+            | Instruction::Ifacmpne(_, _)
+            | Instruction::Ifnull(_, _)
+            | Instruction::Ifnonnull(_, _)
             | Instruction::Getstatic(_)
             | Instruction::Putstatic(_)
             | Instruction::Getfield(_)
             | Instruction::Putfield(_)
             | Instruction::Iinc(_, _)
+            | Instruction::Jsr(_)
             | Instruction::Sipush(_)
+            | Instruction::LdcW(_)
             | Instruction::Ldc2W(_, _)
             | Instruction::Invokevirtual(_)
             | Instruction::Invokespecial(_)
             | Instruction::Invokestatic(_)
             | Instruction::New(_)
-            | Instruction::Anewarray(_) => 2,
+            | Instruction::Anewarray(_)
+            | Instruction::Checkcast(_)
+            | Instruction::Instanceof(_) => 2,
             Instruction::Iload(_)
+            | Instruction::Lload(_)
+            | Instruction::Fload(_)
+            | Instruction::Dload(_)
             | Instruction::Aload(_)
             | Instruction::Istore(_)
+            | Instruction::Lstore(_)
+            | Instruction::Fstore(_)
+            | Instruction::Dstore(_)
             | Instruction::Astore(_)
+            | Instruction::Ret(_)
             | Instruction::Bipush(_)
             | Instruction::Newarray(_)
             | Instruction::Ldc(_) => 1,
@@ -765,36 +1416,365 @@ impl Instruction {
             | Instruction::AstoreN(_)
             | Instruction::AloadN(_)
             | Instruction::Pop
+            | Instruction::Pop2
             | Instruction::Dup
+            | Instruction::DupX1
+            | Instruction::DupX2
+            | Instruction::Dup2
+            | Instruction::Dup2X1
+            | Instruction::Dup2X2
+            | Instruction::Swap
+            | Instruction::Arraylength
+            | Instruction::Athrow
             | Instruction::Iadd
             | Instruction::Ladd
             | Instruction::Fadd
+            | Instruction::Dadd
             | Instruction::Isub
             | Instruction::Lsub
             | Instruction::Fsub
+            | Instruction::Dsub
             | Instruction::Imul
             | Instruction::Lmul
             | Instruction::Fmul
+            | Instruction::Dmul
             | Instruction::Idiv
             | Instruction::Ldiv
             | Instruction::Fdiv
+            | Instruction::Ddiv
             | Instruction::Irem
             | Instruction::Lrem
+            | Instruction::Frem
+            | Instruction::Drem
+            | Instruction::Ineg
+            | Instruction::Lneg
+            | Instruction::Fneg
+            | Instruction::Dneg
+            | Instruction::Ishl
+            | Instruction::Lshl
+            | Instruction::Ishr
+            | Instruction::Lshr
+            | Instruction::Iushr
+            | Instruction::Lushr
+            | Instruction::Iand
+            | Instruction::Land
+            | Instruction::Ior
+            | Instruction::Lor
+            | Instruction::Ixor
+            | Instruction::Lxor
             | Instruction::Lcmp
+            | Instruction::I2l
+            | Instruction::I2f
+            | Instruction::I2d
+            | Instruction::L2i
+            | Instruction::L2f
+            | Instruction::L2d
+            | Instruction::F2i
+            | Instruction::F2l
+            | Instruction::F2d
+            | Instruction::D2i
+            | Instruction::D2l
+            | Instruction::D2f
+            | Instruction::I2b
+            | Instruction::I2c
+            | Instruction::I2s
             | Instruction::Fcmpg
             | Instruction::Fcmpl
+            | Instruction::Dcmpl
+            | Instruction::Dcmpg
             | Instruction::Ireturn
+            | Instruction::Lreturn
+            | Instruction::Freturn
+            | Instruction::Dreturn
             | Instruction::Areturn
             | Instruction::Iaload
             | Instruction::Laload
             | Instruction::Aaload
+            | Instruction::Faload
+            | Instruction::Daload
             | Instruction::Baload
+            | Instruction::Caload
+            | Instruction::Saload
             | Instruction::Iastore
             | Instruction::Lastore
             | Instruction::Aastore
+            | Instruction::Fastore
+            | Instruction::Dastore
             | Instruction::Bastore
+            | Instruction::Castore
+            | Instruction::Sastore
             | Instruction::Return => 0,
             instruction => unimplemented!("{}", instruction),
         }
     }
+
+    // pc is the index in codes, which matches the byte offset because of Noope padding
+    pub fn write(&self, output: &mut Vec<u8>, pc: usize) {
+        // branch targets are kept one before the destination
+        let branch_offset = |target: &usize| target.wrapping_sub(pc);
+        match self {
+            Instruction::Aconstnull => output.push(0x01),
+            Instruction::Iaload => output.push(0x2e),
+            Instruction::Laload => output.push(0x2f),
+            Instruction::Faload => output.push(0x30),
+            Instruction::Daload => output.push(0x31),
+            Instruction::Aaload => output.push(0x32),
+            Instruction::Baload => output.push(0x33),
+            Instruction::Caload => output.push(0x34),
+            Instruction::Saload => output.push(0x35),
+            Instruction::Iastore => output.push(0x4f),
+            Instruction::Lastore => output.push(0x50),
+            Instruction::Fastore => output.push(0x51),
+            Instruction::Dastore => output.push(0x52),
+            Instruction::Aastore => output.push(0x53),
+            Instruction::Bastore => output.push(0x54),
+            Instruction::Castore => output.push(0x55),
+            Instruction::Sastore => output.push(0x56),
+            Instruction::Pop => output.push(0x57),
+            Instruction::Pop2 => output.push(0x58),
+            Instruction::Dup => output.push(0x59),
+            Instruction::DupX1 => output.push(0x5a),
+            Instruction::DupX2 => output.push(0x5b),
+            Instruction::Dup2 => output.push(0x5c),
+            Instruction::Dup2X1 => output.push(0x5d),
+            Instruction::Dup2X2 => output.push(0x5e),
+            Instruction::Swap => output.push(0x5f),
+            Instruction::Iadd => output.push(0x60),
+            Instruction::Ladd => output.push(0x61),
+            Instruction::Fadd => output.push(0x62),
+            Instruction::Dadd => output.push(0x63),
+            Instruction::Isub => output.push(0x64),
+            Instruction::Lsub => output.push(0x65),
+            Instruction::Fsub => output.push(0x66),
+            Instruction::Dsub => output.push(0x67),
+            Instruction::Imul => output.push(0x68),
+            Instruction::Lmul => output.push(0x69),
+            Instruction::Fmul => output.push(0x6a),
+            Instruction::Dmul => output.push(0x6b),
+            Instruction::Idiv => output.push(0x6c),
+            Instruction::Ldiv => output.push(0x6d),
+            Instruction::Fdiv => output.push(0x6e),
+            Instruction::Ddiv => output.push(0x6f),
+            Instruction::Irem => output.push(0x70),
+            Instruction::Lrem => output.push(0x71),
+            Instruction::Frem => output.push(0x72),
+            Instruction::Drem => output.push(0x73),
+            Instruction::Ineg => output.push(0x74),
+            Instruction::Lneg => output.push(0x75),
+            Instruction::Fneg => output.push(0x76),
+            Instruction::Dneg => output.push(0x77),
+            Instruction::Ishl => output.push(0x78),
+            Instruction::Lshl => output.push(0x79),
+            Instruction::Ishr => output.push(0x7a),
+            Instruction::Lshr => output.push(0x7b),
+            Instruction::Iushr => output.push(0x7c),
+            Instruction::Lushr => output.push(0x7d),
+            Instruction::Iand => output.push(0x7e),
+            Instruction::Land => output.push(0x7f),
+            Instruction::Ior => output.push(0x80),
+            Instruction::Lor => output.push(0x81),
+            Instruction::Ixor => output.push(0x82),
+            Instruction::Lxor => output.push(0x83),
+            Instruction::I2l => output.push(0x85),
+            Instruction::I2f => output.push(0x86),
+            Instruction::I2d => output.push(0x87),
+            Instruction::L2i => output.push(0x88),
+            Instruction::L2f => output.push(0x89),
+            Instruction::L2d => output.push(0x8a),
+            Instruction::F2i => output.push(0x8b),
+            Instruction::F2l => output.push(0x8c),
+            Instruction::F2d => output.push(0x8d),
+            Instruction::D2i => output.push(0x8e),
+            Instruction::D2l => output.push(0x8f),
+            Instruction::D2f => output.push(0x90),
+            Instruction::I2b => output.push(0x91),
+            Instruction::I2c => output.push(0x92),
+            Instruction::I2s => output.push(0x93),
+            Instruction::Lcmp => output.push(0x94),
+            Instruction::Fcmpg => output.push(0x95),
+            Instruction::Fcmpl => output.push(0x96),
+            Instruction::Dcmpl => output.push(0x97),
+            Instruction::Dcmpg => output.push(0x98),
+            Instruction::Ireturn => output.push(0xac),
+            Instruction::Lreturn => output.push(0xad),
+            Instruction::Freturn => output.push(0xae),
+            Instruction::Dreturn => output.push(0xaf),
+            Instruction::Areturn => output.push(0xb0),
+            Instruction::Return => output.push(0xb1),
+            Instruction::Arraylength => output.push(0xbe),
+            Instruction::Athrow => output.push(0xbf),
+            Instruction::IconstN(val) => output.push((0x03 + val) as u8),
+            Instruction::LconstN(val) => output.push((0x09 + val) as u8),
+            Instruction::FconstN(val) => output.push(0x0b + *val as u8),
+            Instruction::DconstN(val) => output.push((0x0e + val) as u8),
+            Instruction::IloadN(val) => output.push((0x1a + val) as u8),
+            Instruction::LloadN(val) => output.push((0x1e + val) as u8),
+            Instruction::FloadN(val) => output.push((0x22 + val) as u8),
+            Instruction::DloadN(val) => output.push((0x26 + val) as u8),
+            Instruction::AloadN(val) => output.push((0x2a + val) as u8),
+            Instruction::IstoreN(val) => output.push((0x3b + val) as u8),
+            Instruction::LstoreN(val) => output.push((0x3f + val) as u8),
+            Instruction::FstoreN(val) => output.push((0x43 + val) as u8),
+            Instruction::DstoreN(val) => output.push((0x47 + val) as u8),
+            Instruction::AstoreN(val) => output.push((0x4b + val) as u8),
+            Instruction::Bipush(val) => {
+                output.push(0x10);
+                write_x_byte_as_usize(output, *val as usize, 1);
+            }
+            Instruction::Sipush(val) => {
+                output.push(0x11);
+                write_x_byte_as_usize(output, *val as usize, 2);
+            }
+            Instruction::Ldc(val) => {
+                output.push(0x12);
+                write_x_byte_as_usize(output, *val, 1);
+            }
+            Instruction::LdcW(val) => {
+                output.push(0x13);
+                write_x_byte_as_usize(output, *val, 2);
+            }
+            Instruction::Ldc2W(first, second) => {
+                output.push(0x14);
+                write_x_byte_as_usize(output, *first, 1);
+                write_x_byte_as_usize(output, *second, 1);
+            }
+            Instruction::Iload(val) => write_local_instruction(output, 0x15, *val),
+            Instruction::Lload(val) => write_local_instruction(output, 0x16, *val),
+            Instruction::Fload(val) => write_local_instruction(output, 0x17, *val),
+            Instruction::Dload(val) => write_local_instruction(output, 0x18, *val),
+            Instruction::Aload(val) => write_local_instruction(output, 0x19, *val),
+            Instruction::Istore(val) => write_local_instruction(output, 0x36, *val as usize),
+            Instruction::Lstore(val) => write_local_instruction(output, 0x37, *val),
+            Instruction::Fstore(val) => write_local_instruction(output, 0x38, *val),
+            Instruction::Dstore(val) => write_local_instruction(output, 0x39, *val),
+            Instruction::Astore(val) => write_local_instruction(output, 0x3a, *val),
+            Instruction::Ret(val) => write_local_instruction(output, 0xa9, *val),
+            Instruction::Iinc(val, constant) => {
+                output.push(0x84);
+                write_x_byte_as_usize(output, *val, 1);
+                write_x_byte_as_usize(output, *constant as usize, 1);
+            }
+            Instruction::Ifeq(target, _) => write_branch(output, 0x99, branch_offset(target)),
+            Instruction::Ifne(target, _) => write_branch(output, 0x9a, branch_offset(target)),
+            Instruction::Iflt(target, _) => write_branch(output, 0x9b, branch_offset(target)),
+            Instruction::Ifge(target, _) => write_branch(output, 0x9c, branch_offset(target)),
+            Instruction::Ifgt(target, _) => write_branch(output, 0x9d, branch_offset(target)),
+            Instruction::Ifle(target, _) => write_branch(output, 0x9e, branch_offset(target)),
+            Instruction::Ificmpeq(target, _) => write_branch(output, 0x9f, branch_offset(target)),
+            Instruction::Ificmpne(target, _) => write_branch(output, 0xa0, branch_offset(target)),
+            Instruction::Ificmplt(target, _) => write_branch(output, 0xa1, branch_offset(target)),
+            Instruction::Ificmpge(target, _) => write_branch(output, 0xa2, branch_offset(target)),
+            Instruction::Ificmpgt(target, _) => write_branch(output, 0xa3, branch_offset(target)),
+            Instruction::Ificmple(target, _) => write_branch(output, 0xa4, branch_offset(target)),
+            Instruction::Ifacmpeq(target, _) => write_branch(output, 0xa5, branch_offset(target)),
+            Instruction::Ifacmpne(target, _) => write_branch(output, 0xa6, branch_offset(target)),
+            Instruction::Goto(target) => write_branch(output, 0xa7, branch_offset(target)),
+            Instruction::Jsr(target) => write_branch(output, 0xa8, branch_offset(target)),
+            Instruction::Ifnull(target, _) => write_branch(output, 0xc6, branch_offset(target)),
+            Instruction::Ifnonnull(target, _) => write_branch(output, 0xc7, branch_offset(target)),
+            Instruction::GotoW(target) => {
+                output.push(0xc8);
+                write_x_byte_as_usize(output, branch_offset(target), 4);
+            }
+            Instruction::JsrW(target) => {
+                output.push(0xc9);
+                write_x_byte_as_usize(output, branch_offset(target), 4);
+            }
+            Instruction::Tableswitch(low, high, default, jump_pointers) => {
+                output.push(0xaa);
+                output.extend(vec![0; switch_padding(pc)]);
+                write_x_byte_as_usize(output, branch_offset(default), 4);
+                write_x_byte_as_usize(output, *low as usize, 4);
+                write_x_byte_as_usize(output, *high as usize, 4);
+                for jump_pointer in jump_pointers.iter() {
+                    write_x_byte_as_usize(output, branch_offset(jump_pointer), 4);
+                }
+            }
+            Instruction::Lookupswitch(default, pairs) => {
+                output.push(0xab);
+                output.extend(vec![0; switch_padding(pc)]);
+                write_x_byte_as_usize(output, branch_offset(default), 4);
+                write_x_byte_as_usize(output, pairs.len(), 4);
+                for (key, jump_pointer) in pairs.iter() {
+                    write_x_byte_as_usize(output, *key as usize, 4);
+                    write_x_byte_as_usize(output, branch_offset(jump_pointer), 4);
+                }
+            }
+            Instruction::Getstatic(val) => write_index_instruction(output, 0xb2, *val),
+            Instruction::Putstatic(val) => write_index_instruction(output, 0xb3, *val),
+            Instruction::Getfield(val) => write_index_instruction(output, 0xb4, *val),
+            Instruction::Putfield(val) => write_index_instruction(output, 0xb5, *val),
+            Instruction::Invokevirtual(val) => write_index_instruction(output, 0xb6, *val),
+            Instruction::Invokespecial(val) => write_index_instruction(output, 0xb7, *val),
+            Instruction::Invokestatic(val) => write_index_instruction(output, 0xb8, *val),
+            Instruction::Invokeinterface(val, count) => {
+                write_index_instruction(output, 0xb9, *val);
+                write_x_byte_as_usize(output, *count, 1);
+                output.push(0);
+            }
+            Instruction::New(val) => write_index_instruction(output, 0xbb, *val),
+            Instruction::Newarray(val) => {
+                output.push(0xbc);
+                write_x_byte_as_usize(output, *val, 1);
+            }
+            Instruction::Anewarray(val) => write_index_instruction(output, 0xbd, *val),
+            Instruction::Checkcast(val) => write_index_instruction(output, 0xc0, *val),
+            Instruction::Instanceof(val) => write_index_instruction(output, 0xc1, *val),
+            Instruction::Wide(instruction) => {
+                output.push(0xc4);
+                let mut modified = vec![];
+                instruction.write(&mut modified, pc);
+                output.push(modified[0]);
+                // the index becomes u2 and the constant of iinc becomes i16
+                match **instruction {
+                    Instruction::Iload(index)
+                    | Instruction::Lload(index)
+                    | Instruction::Fload(index)
+                    | Instruction::Dload(index)
+                    | Instruction::Aload(index)
+                    | Instruction::Lstore(index)
+                    | Instruction::Fstore(index)
+                    | Instruction::Dstore(index)
+                    | Instruction::Astore(index)
+                    | Instruction::Ret(index) => write_x_byte_as_usize(output, index, 2),
+                    Instruction::Istore(index) => write_x_byte_as_usize(output, index as usize, 2),
+                    Instruction::Iinc(index, constant) => {
+                        write_x_byte_as_usize(output, index, 2);
+                        write_x_byte_as_usize(output, constant as usize, 2);
+                    }
+                    _ => unreachable!("wide cannot modify {}", instruction),
+                }
+            }
+            Instruction::Multianewarray(val, dimentions) => {
+                write_index_instruction(output, 0xc5, *val);
+                write_x_byte_as_usize(output, *dimentions, 1);
+            }
+            Instruction::Noope => {}
+        }
+    }
+}
+
+fn write_local_instruction(output: &mut Vec<u8>, opcode: u8, index: usize) {
+    output.push(opcode);
+    write_x_byte_as_usize(output, index, 1);
+}
+
+fn write_index_instruction(output: &mut Vec<u8>, opcode: u8, index: usize) {
+    output.push(opcode);
+    write_x_byte_as_usize(output, index, 2);
+}
+
+fn write_branch(output: &mut Vec<u8>, opcode: u8, offset: usize) {
+    output.push(opcode);
+    write_x_byte_as_usize(output, offset, 2);
+}
+
+// switch operands start at an address that is a multiple of four from the start of the method
+fn switch_padding(code_length: usize) -> usize {
+    (4 - (code_length + 1) % 4) % 4
+}
+
+// the offset is signed 32bit and relative to the address of the opecode
+fn wide_jump_pointer(code_length: usize, offset: usize) -> usize {
+    (code_length as i64 + offset as i32 as i64) as usize
 }
@@ -0,0 +1,299 @@
+use crate::attribute::defs::{extract_u2_indexes, write_attribute, write_u2_indexes};
+use crate::utils::{extract_x_byte_as_usize, write_x_byte_as_usize};
+use std::fmt;
+
+#[derive(Debug)]
+pub struct Module {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub module_name_index: u16,    // u2
+    pub module_flags: u16,         // u2
+    pub module_version_index: u16, // u2
+    pub requires_count: usize,     // u2
+    pub requires: Vec<ModuleRequires>,
+    pub exports_count: usize, // u2
+    pub exports: Vec<ModuleExports>,
+    pub opens_count: usize, // u2
+    pub opens: Vec<ModuleOpens>,
+    pub uses_count: usize,     // u2
+    pub uses_index: Vec<u16>,  // u2 uses_index[uses_count]
+    pub provides_count: usize, // u2
+    pub provides: Vec<ModuleProvides>,
+}
+
+impl Module {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (Module, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (module_name_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (module_flags, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (module_version_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+
+        let (requires_count, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut requires = Vec::with_capacity(requires_count);
+        for _ in 0..requires_count {
+            let (requires_index, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (requires_flags, update_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+            let (requires_version_index, update_index) =
+                extract_x_byte_as_usize(inputs, update_index, 2);
+            requires.push(ModuleRequires {
+                requires_index: requires_index as u16,
+                requires_flags: requires_flags as u16,
+                requires_version_index: requires_version_index as u16,
+            });
+            index = update_index;
+        }
+
+        let (exports_count, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut exports = Vec::with_capacity(exports_count);
+        for _ in 0..exports_count {
+            let (exports_index, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (exports_flags, update_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+            let (exports_to_count, update_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+            let (exports_to_index, update_index) =
+                extract_u2_indexes(inputs, update_index, exports_to_count);
+            exports.push(ModuleExports {
+                exports_index: exports_index as u16,
+                exports_flags: exports_flags as u16,
+                exports_to_count,
+                exports_to_index,
+            });
+            index = update_index;
+        }
+
+        let (opens_count, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut opens = Vec::with_capacity(opens_count);
+        for _ in 0..opens_count {
+            let (opens_index, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (opens_flags, update_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+            let (opens_to_count, update_index) = extract_x_byte_as_usize(inputs, update_index, 2);
+            let (opens_to_index, update_index) =
+                extract_u2_indexes(inputs, update_index, opens_to_count);
+            opens.push(ModuleOpens {
+                opens_index: opens_index as u16,
+                opens_flags: opens_flags as u16,
+                opens_to_count,
+                opens_to_index,
+            });
+            index = update_index;
+        }
+
+        let (uses_count, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (uses_index, index) = extract_u2_indexes(inputs, index, uses_count);
+
+        let (provides_count, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut provides = Vec::with_capacity(provides_count);
+        for _ in 0..provides_count {
+            let (provides_index, update_index) = extract_x_byte_as_usize(inputs, index, 2);
+            let (provides_with_count, update_index) =
+                extract_x_byte_as_usize(inputs, update_index, 2);
+            let (provides_with_index, update_index) =
+                extract_u2_indexes(inputs, update_index, provides_with_count);
+            provides.push(ModuleProvides {
+                provides_index: provides_index as u16,
+                provides_with_count,
+                provides_with_index,
+            });
+            index = update_index;
+        }
+
+        (
+            Module {
+                attribute_name_index,
+                attribute_length,
+                module_name_index: module_name_index as u16,
+                module_flags: module_flags as u16,
+                module_version_index: module_version_index as u16,
+                requires_count,
+                requires,
+                exports_count,
+                exports,
+                opens_count,
+                opens,
+                uses_count,
+                uses_index,
+                provides_count,
+                provides,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.module_name_index as usize, 2);
+        write_x_byte_as_usize(&mut info, self.module_flags as usize, 2);
+        write_x_byte_as_usize(&mut info, self.module_version_index as usize, 2);
+
+        write_x_byte_as_usize(&mut info, self.requires.len(), 2);
+        for item in self.requires.iter() {
+            write_x_byte_as_usize(&mut info, item.requires_index as usize, 2);
+            write_x_byte_as_usize(&mut info, item.requires_flags as usize, 2);
+            write_x_byte_as_usize(&mut info, item.requires_version_index as usize, 2);
+        }
+
+        write_x_byte_as_usize(&mut info, self.exports.len(), 2);
+        for item in self.exports.iter() {
+            write_x_byte_as_usize(&mut info, item.exports_index as usize, 2);
+            write_x_byte_as_usize(&mut info, item.exports_flags as usize, 2);
+            write_x_byte_as_usize(&mut info, item.exports_to_index.len(), 2);
+            write_u2_indexes(&mut info, &item.exports_to_index);
+        }
+
+        write_x_byte_as_usize(&mut info, self.opens.len(), 2);
+        for item in self.opens.iter() {
+            write_x_byte_as_usize(&mut info, item.opens_index as usize, 2);
+            write_x_byte_as_usize(&mut info, item.opens_flags as usize, 2);
+            write_x_byte_as_usize(&mut info, item.opens_to_index.len(), 2);
+            write_u2_indexes(&mut info, &item.opens_to_index);
+        }
+
+        write_x_byte_as_usize(&mut info, self.uses_index.len(), 2);
+        write_u2_indexes(&mut info, &self.uses_index);
+
+        write_x_byte_as_usize(&mut info, self.provides.len(), 2);
+        for item in self.provides.iter() {
+            write_x_byte_as_usize(&mut info, item.provides_index as usize, 2);
+            write_x_byte_as_usize(&mut info, item.provides_with_index.len(), 2);
+            write_u2_indexes(&mut info, &item.provides_with_index);
+        }
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Module: #{},{:x},#{}
+  requires: {}
+  exports: {}
+  opens: {}
+  uses: {}
+  provides: {}",
+            self.module_name_index,
+            self.module_flags,
+            self.module_version_index,
+            self.requires_count,
+            self.exports_count,
+            self.opens_count,
+            self.uses_count,
+            self.provides_count
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ModuleRequires {
+    pub requires_index: u16,         // u2
+    pub requires_flags: u16,         // u2
+    pub requires_version_index: u16, // u2
+}
+
+#[derive(Debug)]
+pub struct ModuleExports {
+    pub exports_index: u16,         // u2
+    pub exports_flags: u16,         // u2
+    pub exports_to_count: usize,    // u2
+    pub exports_to_index: Vec<u16>, // u2 exports_to_index[exports_to_count]
+}
+
+#[derive(Debug)]
+pub struct ModuleOpens {
+    pub opens_index: u16,         // u2
+    pub opens_flags: u16,         // u2
+    pub opens_to_count: usize,    // u2
+    pub opens_to_index: Vec<u16>, // u2 opens_to_index[opens_to_count]
+}
+
+#[derive(Debug)]
+pub struct ModuleProvides {
+    pub provides_index: u16,           // u2
+    pub provides_with_count: usize,    // u2
+    pub provides_with_index: Vec<u16>, // u2 provides_with_index[provides_with_count]
+}
+
+#[derive(Debug)]
+pub struct ModulePackages {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub package_count: usize,      // u2
+    pub package_index: Vec<u16>,   // u2 package_index[package_count]
+}
+
+impl ModulePackages {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (ModulePackages, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (package_count, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (package_index, index) = extract_u2_indexes(inputs, index, package_count);
+        (
+            ModulePackages {
+                attribute_name_index,
+                attribute_length,
+                package_count,
+                package_index,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.package_index.len(), 2);
+        write_u2_indexes(&mut info, &self.package_index);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for ModulePackages {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ModulePackages: {}",
+            self.package_index
+                .iter()
+                .map(|index| format!("#{}", index))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ModuleMainClass {
+    pub attribute_name_index: u16, // u2
+    pub attribute_length: u32,     // u4
+    pub main_class_index: u16,     // u2
+}
+
+impl ModuleMainClass {
+    pub fn new(inputs: &[u8], index: usize, attribute_name_index: u16) -> (ModuleMainClass, usize) {
+        let (attribute_length, index) = extract_x_byte_as_usize(inputs, index, 4);
+        let attribute_length = attribute_length as u32;
+
+        let (main_class_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            ModuleMainClass {
+                attribute_name_index,
+                attribute_length,
+                main_class_index: main_class_index as u16,
+            },
+            index,
+        )
+    }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        let mut info = vec![];
+        write_x_byte_as_usize(&mut info, self.main_class_index as usize, 2);
+        write_attribute(output, self.attribute_name_index, &info);
+    }
+}
+
+impl fmt::Display for ModuleMainClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ModuleMainClass: #{}", self.main_class_index)
+    }
+}
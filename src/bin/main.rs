@@ -2,6 +2,7 @@ extern crate clap;
 use clap::{App, Arg};
 
 use r_jvm;
+use std::process;
 
 fn main() {
     let matches = App::new("rj")
@@ -21,7 +22,7 @@ fn main() {
         .get_matches();
 
     if let Some(file_name) = matches.value_of("INPUT") {
-        r_jvm::execute(
+        let status = r_jvm::execute(
             file_name.to_string(),
             matches
                 .value_of("debug")
@@ -29,6 +30,7 @@ fn main() {
                 .parse::<usize>()
                 .unwrap_or(0),
         );
+        process::exit(status);
     } else {
         println!("should input the file");
     }
@@ -1,4 +1,4 @@
-use crate::operand::Item;
+use crate::operand::{create_double_items, create_long_items, Item};
 use crate::string_pool::StringPool;
 use crate::utils::*;
 use std::fmt;
@@ -29,6 +29,14 @@ impl ConstantPool {
                         ConstantMethodref::create_and_update_index(inputs, update_index);
                     (ConstPoolItem::ConstantMethodref(item), update_index)
                 }
+                ConstPoolTag::ConstantInterfaceMethodref => {
+                    let (item, update_index) =
+                        ConstantInterfaceMethodref::create_and_update_index(inputs, update_index);
+                    (
+                        ConstPoolItem::ConstantInterfaceMethodref(item),
+                        update_index,
+                    )
+                }
                 ConstPoolTag::ConstantNameAndType => {
                     let (item, update_index) =
                         ConstantNameAndType::create_and_update_index(inputs, update_index);
@@ -49,11 +57,46 @@ impl ConstantPool {
                         ConstantFieldref::create_and_update_index(inputs, update_index);
                     (ConstPoolItem::ConstantFieldref(item), update_index)
                 }
+                ConstPoolTag::ConstantInteger => {
+                    let (item, update_index) =
+                        ConstantInteger::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantInteger(item), update_index)
+                }
                 ConstPoolTag::ConstantFloat => {
                     let (item, update_index) =
                         ConstantFloat::create_and_update_index(inputs, update_index);
                     (ConstPoolItem::ConstantFloat(item), update_index)
                 }
+                ConstPoolTag::ConstantMethodHandle => {
+                    let (item, update_index) =
+                        ConstantMethodHandle::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantMethodHandle(item), update_index)
+                }
+                ConstPoolTag::ConstantMethodType => {
+                    let (item, update_index) =
+                        ConstantMethodType::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantMethodType(item), update_index)
+                }
+                ConstPoolTag::ConstantDynamic => {
+                    let (item, update_index) =
+                        ConstantDynamic::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantDynamic(item), update_index)
+                }
+                ConstPoolTag::ConstantInvokeDynamic => {
+                    let (item, update_index) =
+                        ConstantInvokeDynamic::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantInvokeDynamic(item), update_index)
+                }
+                ConstPoolTag::ConstantModule => {
+                    let (item, update_index) =
+                        ConstantModule::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantModule(item), update_index)
+                }
+                ConstPoolTag::ConstantPackage => {
+                    let (item, update_index) =
+                        ConstantPackage::create_and_update_index(inputs, update_index);
+                    (ConstPoolItem::ConstantPackage(item), update_index)
+                }
                 ConstPoolTag::ConstantLong => {
                     let (item, update_index) =
                         ConstantLong::create_and_update_index(inputs, update_index);
@@ -72,7 +115,7 @@ impl ConstantPool {
                     items.push(ConstPoolItem::ConstantNull);
                     continue;
                 }
-                _ => unimplemented!(
+                ConstPoolTag::ConstantNull => unreachable!(
                     "
 constant pool purse failed.
 current constant pool
@@ -90,6 +133,14 @@ next tag: {}",
         (ConstantPool(items), index)
     }
 
+    pub fn write(&self, output: &mut Vec<u8>) {
+        // the count includes index 0 and the slots after Long and Double
+        write_x_byte_as_usize(output, self.0.len(), 2);
+        for item in self.0.iter() {
+            item.write(output);
+        }
+    }
+
     pub fn get_main_index(&self) -> Option<usize> {
         self.0.iter().position(|item| {
             if let ConstPoolItem::ConstantUtf8(utf8) = item {
@@ -123,9 +174,16 @@ next tag: {}",
                 }
                 ConstPoolItem::ConstantFieldref(_) => stack.push(Item::Fieldref(index)),
                 ConstPoolItem::ConstantUtf8(item) => stack.push(Item::String(item.id)),
+                ConstPoolItem::ConstantInteger(ref item) => stack.push(Item::Int(item.value())),
                 ConstPoolItem::ConstantLong(ref item) => {
-                    stack.push(Item::Long(item.high_bytes));
-                    stack.push(Item::Long(item.low_bytes));
+                    let (first, second) = create_long_items(item.value());
+                    stack.push(first);
+                    stack.push(second);
+                }
+                ConstPoolItem::ConstantDouble(ref item) => {
+                    let (first, second) = create_double_items(item.value());
+                    stack.push(first);
+                    stack.push(second);
                 }
                 ConstPoolItem::ConstantNull => {
                     unreachable!("index: {}. should not come ConstantNull", index)
@@ -179,6 +237,16 @@ next tag: {}",
         }
     }
 
+    pub fn get_interface_method_ref(&self, index: usize) -> &ConstantInterfaceMethodref {
+        match self.0.get(index) {
+            Some(ConstPoolItem::ConstantInterfaceMethodref(ref item)) => item,
+            _ => unreachable!(
+                "should be ConstantInterfaceMethodref. actual {:?}",
+                self.0.get(index)
+            ),
+        }
+    }
+
     pub fn get_field_ref(&self, index: usize) -> &ConstantFieldref {
         match self.0.get(index) {
             Some(ConstPoolItem::ConstantFieldref(ref item)) => item,
@@ -193,6 +261,13 @@ next tag: {}",
         }
     }
 
+    pub fn get_integer(&self, index: usize) -> i32 {
+        match self.0.get(index) {
+            Some(ConstPoolItem::ConstantInteger(ref item)) => item.value(),
+            _ => unreachable!("should be ConstantInteger. actual {:?}", self.0.get(index)),
+        }
+    }
+
     pub fn get_float(&self, index: usize) -> f32 {
         match self.0.get(index) {
             Some(ConstPoolItem::ConstantFloat(ConstantFloat { bytes, .. })) => {
@@ -210,9 +285,26 @@ next tag: {}",
         }
     }
 
+    // the value of the field which has ConstantValue attribute
+    pub fn get_constant_value(&self, index: usize) -> (Item, Item) {
+        match self.0.get(index) {
+            Some(ConstPoolItem::ConstantInteger(item)) => (Item::Int(item.value()), Item::Null),
+            Some(ConstPoolItem::ConstantLong(item)) => create_long_items(item.value()),
+            Some(ConstPoolItem::ConstantDouble(item)) => create_double_items(item.value()),
+            Some(ConstPoolItem::ConstantFloat(_)) => {
+                (Item::Float(self.get_float(index)), Item::Null)
+            }
+            Some(ConstPoolItem::ConstantString(_)) => {
+                (Item::String(self.get_string(index)), Item::Null)
+            }
+            _ => unimplemented!("{:?} is not supported as ConstantValue", self.0.get(index)),
+        }
+    }
+
     pub fn get_item_tag(&self, index: usize) -> ConstPoolTag {
         match self.0.get(index) {
             Some(ConstPoolItem::ConstantString(_)) => ConstPoolTag::ConstantString,
+            Some(ConstPoolItem::ConstantInteger(_)) => ConstPoolTag::ConstantInteger,
             Some(ConstPoolItem::ConstantFloat(_)) => ConstPoolTag::ConstantFloat,
             _ => unimplemented!(),
         }
@@ -253,6 +345,10 @@ impl fmt::Display for ConstantPool {
                     "  #{} = Methodref        #{}.#{}",
                     index, item.class_index, item.name_and_type_index,
                 ),
+                ConstPoolItem::ConstantInterfaceMethodref(item) => format!(
+                    "  #{} = InterfaceMethodref #{}.#{}",
+                    index, item.class_index, item.name_and_type_index,
+                ),
                 ConstPoolItem::ConstantFieldref(item) => format!(
                     "  #{} = Fieldref         #{}.#{}",
                     index, item.class_index, item.name_and_type_index
@@ -266,23 +362,45 @@ impl fmt::Display for ConstantPool {
                 ConstPoolItem::ConstantUtf8(item) => format!(
                     "  #{} = Utf8             {}",
                     index,
-                    String::from_utf8_lossy(item.bytes.as_slice())
+                    decode_modified_utf8(item.bytes.as_slice())
                 ),
                 ConstPoolItem::ConstantNameAndType(item) => format!(
                     "  #{} = NameAndType      #{}:#{}",
                     index, item.name_index, item.descriptor_index
                 ),
-                ConstPoolItem::ConstantLong(item) => format!(
-                    "  #{} = Long             {}l",
-                    index,
-                    ((item.high_bytes as i64) << 32) as i64 | item.low_bytes as i64
+                ConstPoolItem::ConstantLong(item) => {
+                    format!("  #{} = Long             {}l", index, item.value())
+                }
+                ConstPoolItem::ConstantDouble(item) => {
+                    format!("  #{} = Double           {}d", index, item.value())
+                }
+                ConstPoolItem::ConstantInteger(item) => {
+                    format!("  #{} = Integer          {}", index, item.value())
+                }
+                ConstPoolItem::ConstantFloat(_) => {
+                    format!("  #{} = Float            {}f", index, self.get_float(index))
+                }
+                ConstPoolItem::ConstantMethodHandle(item) => format!(
+                    "  #{} = MethodHandle     {}:#{}",
+                    index, item.reference_kind, item.reference_index
                 ),
-                ConstPoolItem::ConstantDouble(item) => format!(
-                    "  #{} = Double           {}",
-                    index,
-                    ((item.high_bytes << 8) | item.low_bytes) & 0xFFFF
+                ConstPoolItem::ConstantMethodType(item) => {
+                    format!("  #{} = MethodType       #{}", index, item.descriptor_index)
+                }
+                ConstPoolItem::ConstantDynamic(item) => format!(
+                    "  #{} = Dynamic          #{}:#{}",
+                    index, item.bootstrap_method_attr_index, item.name_and_type_index
+                ),
+                ConstPoolItem::ConstantInvokeDynamic(item) => format!(
+                    "  #{} = InvokeDynamic    #{}:#{}",
+                    index, item.bootstrap_method_attr_index, item.name_and_type_index
                 ),
-                _ => unimplemented!(),
+                ConstPoolItem::ConstantModule(item) => {
+                    format!("  #{} = Module           #{}", index, item.name_index)
+                }
+                ConstPoolItem::ConstantPackage(item) => {
+                    format!("  #{} = Package          #{}", index, item.name_index)
+                }
             };
             result.push(rw);
         }
@@ -306,7 +424,10 @@ pub enum ConstPoolTag {
     ConstantUtf8 = 1,
     ConstantMethodHandle = 15,
     ConstantMethodType = 16,
+    ConstantDynamic = 17,
     ConstantInvokeDynamic = 18,
+    ConstantModule = 19,
+    ConstantPackage = 20,
 }
 
 impl From<usize> for ConstPoolTag {
@@ -326,7 +447,10 @@ impl From<usize> for ConstPoolTag {
             1 => ConstPoolTag::ConstantUtf8,
             15 => ConstPoolTag::ConstantMethodHandle,
             16 => ConstPoolTag::ConstantMethodType,
+            17 => ConstPoolTag::ConstantDynamic,
             18 => ConstPoolTag::ConstantInvokeDynamic,
+            19 => ConstPoolTag::ConstantModule,
+            20 => ConstPoolTag::ConstantPackage,
             _ => panic!("failed to convert {} to ConstPoolTag", num),
         }
     }
@@ -338,17 +462,106 @@ pub enum ConstPoolItem {
     ConstantClass(ConstantClass),
     ConstantFieldref(ConstantFieldref),
     ConstantMethodref(ConstantMethodref),
-    ConstantInterfaceMethodref,
+    ConstantInterfaceMethodref(ConstantInterfaceMethodref),
     ConstantString(ConstantString),
-    ConstantInteger,
+    ConstantInteger(ConstantInteger),
     ConstantFloat(ConstantFloat),
     ConstantLong(ConstantLong),
     ConstantDouble(ConstantDouble),
     ConstantNameAndType(ConstantNameAndType),
     ConstantUtf8(ConstantUtf8),
-    ConstantMethodHandle,
-    ConstantMethodType,
-    ConstantInvokeDynamic,
+    ConstantMethodHandle(ConstantMethodHandle),
+    ConstantMethodType(ConstantMethodType),
+    ConstantDynamic(ConstantDynamic),
+    ConstantInvokeDynamic(ConstantInvokeDynamic),
+    ConstantModule(ConstantModule),
+    ConstantPackage(ConstantPackage),
+}
+
+impl ConstPoolItem {
+    pub fn write(&self, output: &mut Vec<u8>) {
+        match self {
+            ConstPoolItem::ConstantNull => {}
+            ConstPoolItem::ConstantClass(item) => {
+                output.push(ConstPoolTag::ConstantClass as u8);
+                write_x_byte_as_usize(output, item.name_index, 2);
+            }
+            ConstPoolItem::ConstantFieldref(item) => {
+                output.push(ConstPoolTag::ConstantFieldref as u8);
+                write_x_byte_as_usize(output, item.class_index, 2);
+                write_x_byte_as_usize(output, item.name_and_type_index, 2);
+            }
+            ConstPoolItem::ConstantMethodref(item) => {
+                output.push(ConstPoolTag::ConstantMethodref as u8);
+                write_x_byte_as_usize(output, item.class_index, 2);
+                write_x_byte_as_usize(output, item.name_and_type_index, 2);
+            }
+            ConstPoolItem::ConstantInterfaceMethodref(item) => {
+                output.push(ConstPoolTag::ConstantInterfaceMethodref as u8);
+                write_x_byte_as_usize(output, item.class_index, 2);
+                write_x_byte_as_usize(output, item.name_and_type_index, 2);
+            }
+            ConstPoolItem::ConstantString(item) => {
+                output.push(ConstPoolTag::ConstantString as u8);
+                write_x_byte_as_usize(output, item.string_index, 2);
+            }
+            ConstPoolItem::ConstantInteger(item) => {
+                output.push(ConstPoolTag::ConstantInteger as u8);
+                write_x_byte_as_usize(output, item.bytes, 4);
+            }
+            ConstPoolItem::ConstantFloat(item) => {
+                output.push(ConstPoolTag::ConstantFloat as u8);
+                write_x_byte_as_usize(output, item.bytes, 4);
+            }
+            ConstPoolItem::ConstantLong(item) => {
+                output.push(ConstPoolTag::ConstantLong as u8);
+                write_x_byte_as_usize(output, item.high_bytes, 4);
+                write_x_byte_as_usize(output, item.low_bytes, 4);
+            }
+            ConstPoolItem::ConstantDouble(item) => {
+                output.push(ConstPoolTag::ConstantDouble as u8);
+                write_x_byte_as_usize(output, item.high_bytes, 4);
+                write_x_byte_as_usize(output, item.low_bytes, 4);
+            }
+            ConstPoolItem::ConstantNameAndType(item) => {
+                output.push(ConstPoolTag::ConstantNameAndType as u8);
+                write_x_byte_as_usize(output, item.name_index, 2);
+                write_x_byte_as_usize(output, item.descriptor_index, 2);
+            }
+            ConstPoolItem::ConstantUtf8(item) => {
+                output.push(ConstPoolTag::ConstantUtf8 as u8);
+                write_x_byte_as_usize(output, item.bytes.len(), 2);
+                output.extend_from_slice(&item.bytes);
+            }
+            ConstPoolItem::ConstantMethodHandle(item) => {
+                output.push(ConstPoolTag::ConstantMethodHandle as u8);
+                write_x_byte_as_usize(output, item.reference_kind, 1);
+                write_x_byte_as_usize(output, item.reference_index, 2);
+            }
+            ConstPoolItem::ConstantMethodType(item) => {
+                output.push(ConstPoolTag::ConstantMethodType as u8);
+                write_x_byte_as_usize(output, item.descriptor_index, 2);
+            }
+            ConstPoolItem::ConstantDynamic(item) => {
+                output.push(ConstPoolTag::ConstantDynamic as u8);
+                write_x_byte_as_usize(output, item.bootstrap_method_attr_index, 2);
+                write_x_byte_as_usize(output, item.name_and_type_index, 2);
+            }
+            ConstPoolItem::ConstantInvokeDynamic(item) => {
+                output.push(ConstPoolTag::ConstantInvokeDynamic as u8);
+                write_x_byte_as_usize(output, item.bootstrap_method_attr_index, 2);
+                write_x_byte_as_usize(output, item.name_and_type_index, 2);
+            }
+            ConstPoolItem::ConstantModule(item) => {
+                output.push(ConstPoolTag::ConstantModule as u8);
+                write_x_byte_as_usize(output, item.name_index, 2);
+            }
+            ConstPoolItem::ConstantPackage(item) => {
+                output.push(ConstPoolTag::ConstantPackage as u8);
+                write_x_byte_as_usize(output, item.name_index, 2);
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -359,12 +572,16 @@ pub struct ConstantLong {
 }
 
 impl ConstantLong {
+    pub fn value(&self) -> i64 {
+        ((self.high_bytes as u64) << 32 | self.low_bytes as u64) as i64
+    }
+
     pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantLong, usize) {
         let (high_bytes, index) = extract_x_byte_as_usize(inputs, index, 4);
         let (low_bytes, index) = extract_x_byte_as_usize(inputs, index, 4);
         (
             ConstantLong {
-                tag: ConstPoolTag::ConstantString,
+                tag: ConstPoolTag::ConstantLong,
                 high_bytes,
                 low_bytes,
             },
@@ -381,12 +598,16 @@ pub struct ConstantDouble {
 }
 
 impl ConstantDouble {
+    pub fn value(&self) -> f64 {
+        f64::from_bits((self.high_bytes as u64) << 32 | self.low_bytes as u64)
+    }
+
     pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantDouble, usize) {
         let (high_bytes, index) = extract_x_byte_as_usize(inputs, index, 4);
         let (low_bytes, index) = extract_x_byte_as_usize(inputs, index, 4);
         (
             ConstantDouble {
-                tag: ConstPoolTag::ConstantString,
+                tag: ConstPoolTag::ConstantDouble,
                 high_bytes,
                 low_bytes,
             },
@@ -414,6 +635,29 @@ impl ConstantString {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub struct ConstantInteger {
+    pub tag: ConstPoolTag,
+    pub bytes: usize, // u4
+}
+
+impl ConstantInteger {
+    pub fn value(&self) -> i32 {
+        self.bytes as u32 as i32
+    }
+
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantInteger, usize) {
+        let (bytes, index) = extract_x_byte_as_usize(inputs, index, 4);
+        (
+            ConstantInteger {
+                tag: ConstPoolTag::ConstantInteger,
+                bytes,
+            },
+            index,
+        )
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ConstantFloat {
     pub tag: ConstPoolTag,
@@ -520,6 +764,155 @@ impl ConstantMethodref {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub struct ConstantInterfaceMethodref {
+    pub tag: ConstPoolTag,
+    pub class_index: usize,         // u2
+    pub name_and_type_index: usize, // u2
+}
+
+impl ConstantInterfaceMethodref {
+    pub fn create_and_update_index(
+        inputs: &[u8],
+        index: usize,
+    ) -> (ConstantInterfaceMethodref, usize) {
+        let (class_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (name_and_type_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+
+        (
+            ConstantInterfaceMethodref {
+                tag: ConstPoolTag::ConstantInterfaceMethodref,
+                class_index,
+                name_and_type_index,
+            },
+            index,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConstantMethodHandle {
+    pub tag: ConstPoolTag,
+    pub reference_kind: usize,  // u1
+    pub reference_index: usize, // u2
+}
+
+impl ConstantMethodHandle {
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantMethodHandle, usize) {
+        let (reference_kind, index) = extract_x_byte_as_usize(inputs, index, 1);
+        let (reference_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            ConstantMethodHandle {
+                tag: ConstPoolTag::ConstantMethodHandle,
+                reference_kind,
+                reference_index,
+            },
+            index,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConstantMethodType {
+    pub tag: ConstPoolTag,
+    pub descriptor_index: usize, // u2
+}
+
+impl ConstantMethodType {
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantMethodType, usize) {
+        let (descriptor_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            ConstantMethodType {
+                tag: ConstPoolTag::ConstantMethodType,
+                descriptor_index,
+            },
+            index,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConstantDynamic {
+    pub tag: ConstPoolTag,
+    pub bootstrap_method_attr_index: usize, // u2
+    pub name_and_type_index: usize,         // u2
+}
+
+impl ConstantDynamic {
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantDynamic, usize) {
+        let (bootstrap_method_attr_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (name_and_type_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            ConstantDynamic {
+                tag: ConstPoolTag::ConstantDynamic,
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            },
+            index,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConstantInvokeDynamic {
+    pub tag: ConstPoolTag,
+    pub bootstrap_method_attr_index: usize, // u2
+    pub name_and_type_index: usize,         // u2
+}
+
+impl ConstantInvokeDynamic {
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantInvokeDynamic, usize) {
+        let (bootstrap_method_attr_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (name_and_type_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            ConstantInvokeDynamic {
+                tag: ConstPoolTag::ConstantInvokeDynamic,
+                bootstrap_method_attr_index,
+                name_and_type_index,
+            },
+            index,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConstantModule {
+    pub tag: ConstPoolTag,
+    pub name_index: usize, // u2
+}
+
+impl ConstantModule {
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantModule, usize) {
+        let (name_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            ConstantModule {
+                tag: ConstPoolTag::ConstantModule,
+                name_index,
+            },
+            index,
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ConstantPackage {
+    pub tag: ConstPoolTag,
+    pub name_index: usize, // u2
+}
+
+impl ConstantPackage {
+    pub fn create_and_update_index(inputs: &[u8], index: usize) -> (ConstantPackage, usize) {
+        let (name_index, index) = extract_x_byte_as_usize(inputs, index, 2);
+        (
+            ConstantPackage {
+                tag: ConstPoolTag::ConstantPackage,
+                name_index,
+            },
+            index,
+        )
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ConstantUtf8 {
     pub id: usize, // custom value
@@ -536,8 +929,8 @@ impl ConstantUtf8 {
     ) -> (ConstantUtf8, usize) {
         let (utf8_length, index) = extract_x_byte_as_usize(inputs, index, 2);
         let (bytes, index) = extract_x_byte_as_vec(inputs, index, utf8_length);
-        let value = String::from_utf8_lossy(bytes.as_slice());
-        let id = string_map.insert(value.to_string());
+        let value = decode_modified_utf8(bytes.as_slice());
+        let id = string_map.insert(value);
 
         (
             ConstantUtf8 {
@@ -580,6 +973,149 @@ mod test {
         );
     }
 
+    #[test]
+    fn constant_pool_constant_interface_methodref() {
+        let mut inputs = vec![
+            0x0b, // interface_methodref
+            0x00, 0x0a, // class_index
+            0x00, 0x0b, // name_and_type_index
+        ];
+
+        let result = ConstantPool::new(&mut StringPool::new(), &mut inputs, 0, 2);
+
+        assert_eq!(
+            result,
+            (
+                ConstantPool(vec![
+                    ConstPoolItem::ConstantNull,
+                    ConstPoolItem::ConstantInterfaceMethodref(ConstantInterfaceMethodref {
+                        tag: ConstPoolTag::ConstantInterfaceMethodref,
+                        class_index: 0x0a,
+                        name_and_type_index: 0x0b
+                    })
+                ]),
+                inputs.len()
+            )
+        );
+    }
+
+    #[test]
+    fn constant_pool_constant_integer() {
+        let mut inputs = vec![
+            0x03, // integer
+            0xff, 0xfe, 0x79, 0x60, // bytes
+        ];
+
+        let (constant_pool, index) = ConstantPool::new(&mut StringPool::new(), &mut inputs, 0, 2);
+
+        assert_eq!(index, inputs.len());
+        assert_eq!(constant_pool.get_integer(1), -100000);
+        assert_eq!(
+            format!("{}", constant_pool),
+            "  #1 = Integer          -100000"
+        );
+    }
+
+    #[test]
+    fn constant_pool_constant_long_and_double() {
+        let mut inputs = vec![
+            0x05, // long
+            0x00, 0x00, 0x00, 0x01, // high_bytes
+            0x00, 0x00, 0x00, 0x02, // low_bytes
+            0x06, // double
+            0x3f, 0xf8, 0x00, 0x00, // high_bytes
+            0x00, 0x00, 0x00, 0x00, // low_bytes
+        ];
+
+        let (constant_pool, index) = ConstantPool::new(&mut StringPool::new(), &mut inputs, 0, 5);
+
+        assert_eq!(index, inputs.len());
+        assert_eq!(
+            constant_pool.0[1],
+            ConstPoolItem::ConstantLong(ConstantLong {
+                tag: ConstPoolTag::ConstantLong,
+                high_bytes: 1,
+                low_bytes: 2,
+            })
+        );
+        assert_eq!(
+            constant_pool.0[3],
+            ConstPoolItem::ConstantDouble(ConstantDouble {
+                tag: ConstPoolTag::ConstantDouble,
+                high_bytes: 0x3ff80000,
+                low_bytes: 0,
+            })
+        );
+
+        let mut output = vec![];
+        constant_pool.write(&mut output);
+        assert_eq!(output[..2], [0x00, 0x05]); // constant_pool_count
+        assert_eq!(output[2..], inputs[..]);
+    }
+
+    #[test]
+    fn constant_pool_constant_method_handle_and_invoke_dynamic() {
+        let mut inputs = vec![
+            0x0f, // method_handle
+            0x06, // reference_kind
+            0x00, 0x0a, // reference_index
+            0x10, // method_type
+            0x00, 0x0b, // descriptor_index
+            0x12, // invoke_dynamic
+            0x00, 0x00, // bootstrap_method_attr_index
+            0x00, 0x0c, // name_and_type_index
+        ];
+
+        let result = ConstantPool::new(&mut StringPool::new(), &mut inputs, 0, 4);
+
+        assert_eq!(
+            result,
+            (
+                ConstantPool(vec![
+                    ConstPoolItem::ConstantNull,
+                    ConstPoolItem::ConstantMethodHandle(ConstantMethodHandle {
+                        tag: ConstPoolTag::ConstantMethodHandle,
+                        reference_kind: 0x06,
+                        reference_index: 0x0a
+                    }),
+                    ConstPoolItem::ConstantMethodType(ConstantMethodType {
+                        tag: ConstPoolTag::ConstantMethodType,
+                        descriptor_index: 0x0b
+                    }),
+                    ConstPoolItem::ConstantInvokeDynamic(ConstantInvokeDynamic {
+                        tag: ConstPoolTag::ConstantInvokeDynamic,
+                        bootstrap_method_attr_index: 0x00,
+                        name_and_type_index: 0x0c
+                    })
+                ]),
+                inputs.len()
+            )
+        );
+    }
+
+    #[test]
+    fn constant_pool_constant_dynamic_module_and_package() {
+        let mut inputs = vec![
+            0x11, // dynamic
+            0x00, 0x01, // bootstrap_method_attr_index
+            0x00, 0x0c, // name_and_type_index
+            0x13, // module
+            0x00, 0x0d, // name_index
+            0x14, // package
+            0x00, 0x0e, // name_index
+        ];
+
+        let (constant_pool, index) = ConstantPool::new(&mut StringPool::new(), &mut inputs, 0, 4);
+
+        assert_eq!(index, inputs.len());
+        assert_eq!(
+            format!("{}", constant_pool),
+            "  #1 = Dynamic          #1:#12
+  #2 = Module           #13
+  #3 = Package          #14"
+        );
+    }
+
     #[test]
     fn constant_pool_constant_class() {
         let mut inputs = vec![
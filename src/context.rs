@@ -1,21 +1,21 @@
-use crate::array::{Array, ArrayMap};
+use crate::array::{object_array_class_name, primitive_array_class_name, Array, ArrayMap};
 use crate::attribute::code::Code;
 use crate::attribute::instruction::Instruction;
-use crate::constant::{ConstPoolTag, ConstantNameAndType, ConstantPool};
+use crate::constant::{ConstPoolItem, ConstPoolTag, ConstantNameAndType, ConstantPool};
 use crate::field::{BaseType, FieldDescriptor};
 use crate::java_class::{custom::Custom, JavaClass};
+use crate::method::parameter_slot_length;
 use crate::object::{ObjectMap, Objectref};
-use crate::operand::Item;
+use crate::operand::{create_double_items, create_long_items, Item};
 
 use crate::stackframe::Stackframe;
 use crate::string_pool::StringPool;
-use crate::utils::{emit_debug_info, iniailize_primitive_array, initialize_objectref_array};
-use crate::wasm::get_file_content;
+use crate::utils::{emit_debug_info, iniailize_primitive_array};
+use crate::wasm::{get_file_content, has_file, print_error_log};
 
 use std::cell::RefCell;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::mem;
 use std::path::Path;
 
 #[derive(Debug)]
@@ -27,11 +27,77 @@ pub struct Context<'a> {
     pub static_fields: StaticFields,
     pub object_map: ObjectMap,
     pub array_map: ArrayMap,
+    // the exception which is thrown and not caught yet
+    pub thrown_exception: Option<usize>,
+    pub class_summaries: HashMap<usize, ClassSummary>,
+    pub vtables: HashMap<usize, Vtable>,
+    pub class_states: HashMap<usize, ClassState>,
+}
+
+// JVMS 5.5. the class which is not in class_states is not initialized yet
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ClassState {
+    BeingInitialized,
+    Initialized,
+    // <clinit> threw an exception
+    Erroneous,
 }
 
 pub type ClassMap = HashMap<usize, JavaClass>;
-// class_name, field_name
-pub type StaticFields = HashMap<(usize, usize), (Item, Item)>;
+// (method_name, method_descriptor), class_name which has the selected method
+pub type Vtable = HashMap<(usize, usize), usize>;
+
+// the part of the class which is needed to walk the hierarchy without loading it
+#[derive(Debug, Clone)]
+pub struct ClassSummary {
+    pub super_class_name: Option<usize>,
+    pub interface_names: Vec<usize>,
+    // (method_name, method_descriptor) of the methods which have code
+    pub methods: Vec<(usize, usize)>,
+    // (field_name, field_descriptor) of the fields which are not static
+    pub instance_fields: Vec<(usize, usize)>,
+    // field_name of the static fields
+    pub static_fields: Vec<usize>,
+}
+
+impl ClassSummary {
+    fn new(class_file: &Custom) -> ClassSummary {
+        ClassSummary {
+            super_class_name: class_file.super_class_name(),
+            interface_names: class_file.interface_names(),
+            methods: class_file
+                .methods
+                .iter()
+                .filter(|method| method.extract_code().is_some())
+                .map(|method| {
+                    (
+                        class_file.cp_info.get_utf8(method.name_index),
+                        class_file.cp_info.get_utf8(method.descriptor_index),
+                    )
+                })
+                .collect(),
+            instance_fields: class_file
+                .fields
+                .iter()
+                .filter(|field| !field.is_static())
+                .map(|field| {
+                    (
+                        class_file.cp_info.get_utf8(field.name_index),
+                        class_file.cp_info.get_utf8(field.descriptor_index),
+                    )
+                })
+                .collect(),
+            static_fields: class_file
+                .fields
+                .iter()
+                .filter(|field| field.is_static())
+                .map(|field| class_file.cp_info.get_utf8(field.name_index))
+                .collect(),
+        }
+    }
+}
+// class_name -> field_name -> value
+pub type StaticFields = HashMap<usize, HashMap<usize, (Item, Item)>>;
 
 impl<'a> Context<'a> {
     pub fn new(
@@ -40,8 +106,22 @@ impl<'a> Context<'a> {
         class_file: &Custom,
         root_path: &'a str,
     ) -> Context<'a> {
-        let mut static_fields = setup_static_fields(string_map, &class_map);
-        set_static_fields(string_map, &class_file, &mut static_fields);
+        let static_fields = setup_static_fields(string_map, &class_map);
+        let mut class_summaries = HashMap::new();
+        for (class_name, class) in class_map.iter() {
+            if let JavaClass::BuiltIn(builtin) = class {
+                // methods of builtin classes are found in class_map
+                let summary = ClassSummary {
+                    super_class_name: builtin.super_class_name,
+                    interface_names: builtin.interface_names.clone(),
+                    methods: vec![],
+                    instance_fields: vec![],
+                    static_fields: vec![],
+                };
+                class_summaries.insert(*class_name, summary);
+            }
+        }
+        class_summaries.insert(class_file.this_class_name(), ClassSummary::new(class_file));
 
         Context {
             class_map,
@@ -51,10 +131,15 @@ impl<'a> Context<'a> {
             static_fields,
             object_map: ObjectMap::new(),
             array_map: ArrayMap::new(),
+            thrown_exception: None,
+            class_summaries,
+            vtables: HashMap::new(),
+            class_states: HashMap::new(),
         }
     }
 
-    pub fn run_entry_file(&mut self, string_map: &mut StringPool, class_file: Custom) {
+    // returns the exit status
+    pub fn run_entry_file(&mut self, string_map: &mut StringPool, class_file: Custom) -> i32 {
         let entry_method = class_file
             .get_entry_method()
             .expect("add handler in the case failed to find entry method");
@@ -65,10 +150,8 @@ impl<'a> Context<'a> {
         let super_class_name = class_file.cp_info.get_utf8(super_class_ref.name_index);
         let stack_frame_item_0 = Item::Classref(super_class_name);
 
-        if let Some(code) = class_file.get_clinit_code() {
-            let stack_frame = Stackframe::new(code.max_locals as usize);
-            self.stack_frames.push(stack_frame);
-            self.call_custom_class_method(string_map, &class_file, code);
+        if !self.initialize_class(string_map, class_file.this_class_name()) {
+            return self.handle_uncaught_exception(string_map);
         }
 
         let code = entry_method
@@ -79,8 +162,113 @@ impl<'a> Context<'a> {
         self.stack_frames.push(stack_frame);
         self.run_method(string_map, &class_file, code);
 
+        self.handle_uncaught_exception(string_map)
+    }
+
+    // JVMS 5.5. returns false if the initialization throws an exception
+    fn initialize_class(&mut self, string_map: &mut StringPool, class_name: usize) -> bool {
+        match self.class_states.get(&class_name) {
+            // BeingInitialized means the request is recursive from <clinit>
+            Some(ClassState::BeingInitialized) | Some(ClassState::Initialized) => return true,
+            Some(ClassState::Erroneous) => {
+                let message = format!(
+                    "Could not initialize class {}",
+                    string_map.get_value(&class_name).replace("/", ".")
+                );
+                self.throw_exception(string_map, "java/lang/NoClassDefFoundError", Some(message));
+                return false;
+            }
+            None => {}
+        }
+        let class_path =
+            Path::new(self.root_path).join(string_map.get_value(&class_name) + ".class");
+        if let Some(JavaClass::BuiltIn(_)) = self.class_map.get(&class_name) {
+            self.class_states
+                .insert(class_name, ClassState::Initialized);
+            return true;
+        } else if !has_file(class_path.to_str().unwrap()) {
+            unreachable!("{} is not found", string_map.get_value(&class_name));
+        }
+
+        self.class_states
+            .insert(class_name, ClassState::BeingInitialized);
+        let class_file = self.create_custom_class(string_map, class_name);
+        // preparation
+        set_static_fields(string_map, &class_file, &mut self.static_fields);
+        if let Some(super_class_name) = class_file.super_class_name() {
+            if !self.initialize_class(string_map, super_class_name) {
+                self.class_states.insert(class_name, ClassState::Erroneous);
+                return false;
+            }
+        }
+        if let Some(code) = class_file.get_clinit_code() {
+            let stack_frame = Stackframe::new(code.max_locals as usize);
+            self.stack_frames.push(stack_frame);
+            self.run_method(string_map, &class_file, code);
+        }
         self.class_map
-            .insert(class_file.this_class_name(), JavaClass::Custom(class_file));
+            .entry(class_name)
+            .or_insert(JavaClass::Custom(class_file));
+
+        if let Some(exception_id) = self.thrown_exception {
+            self.class_states.insert(class_name, ClassState::Erroneous);
+            let exception_class_name = self
+                .object_map
+                .get(&exception_id)
+                .expect("should exist exception in object_map")
+                .class_name_id;
+            let error_class_name = string_map.insert(String::from("java/lang/Error"));
+            // Error is thrown as it is
+            if !self.is_subclass_of(string_map, exception_class_name, error_class_name) {
+                let error_id =
+                    self.throw_exception(string_map, "java/lang/ExceptionInInitializerError", None);
+                let throwable_name = string_map.insert(String::from("java/lang/Throwable"));
+                let cause_name = string_map.insert(String::from("cause"));
+                self.object_map
+                    .get(&error_id)
+                    .expect("should exist exception in object_map")
+                    .field_map
+                    .borrow_mut()
+                    .insert(
+                        (throwable_name, cause_name),
+                        (Item::Objectref(exception_id), Item::Null),
+                    );
+            }
+            return false;
+        }
+        self.class_states
+            .insert(class_name, ClassState::Initialized);
+        true
+    }
+
+    fn handle_uncaught_exception(&mut self, string_map: &mut StringPool) -> i32 {
+        let exception_id = if let Some(exception_id) = self.thrown_exception.take() {
+            exception_id
+        } else {
+            return 0;
+        };
+        let exception = self
+            .object_map
+            .get(&exception_id)
+            .expect("should exist exception in object_map");
+        let class_name = string_map
+            .get_value(&exception.class_name_id)
+            .replace("/", ".");
+        let throwable_name = string_map.insert(String::from("java/lang/Throwable"));
+        let field_name = string_map.insert(String::from("detailMessage"));
+        let message = match exception
+            .field_map
+            .borrow()
+            .get(&(throwable_name, field_name))
+        {
+            Some((Item::String(id), _)) => format!(": {}", string_map.get_value(id)),
+            _ => String::new(),
+        };
+        print_error_log(&format!(
+            "Exception in thread \"main\" {}{}",
+            class_name, message
+        ));
+        1
     }
 
     fn run_method(&mut self, string_map: &mut StringPool, class_file: &Custom, code: &Code) {
@@ -89,14 +277,301 @@ impl<'a> Context<'a> {
             emit_debug_info(instruction, self.stack_frames.last());
             let (should_finish, update_index) =
                 self.execute(string_map, class_file, instruction, index);
+            if let Some(exception_id) = self.thrown_exception {
+                // the exception which is not caught here is passed to the caller
+                match self.find_exception_handler(string_map, class_file, code, index, exception_id)
+                {
+                    Some(handler_pc) => {
+                        self.thrown_exception = None;
+                        let operand_stack = self.get_operand_stack();
+                        operand_stack.clear();
+                        operand_stack.push(Item::Objectref(exception_id));
+                        index = handler_pc;
+                        continue;
+                    }
+                    None => break,
+                }
+            }
             if should_finish {
                 break;
             }
-            index = update_index + 1;
+            index = update_index;
         }
         self.stack_frames.pop();
     }
 
+    fn find_exception_handler(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        code: &Code,
+        index: usize,
+        exception_id: usize,
+    ) -> Option<usize> {
+        let exception_class_name = self
+            .object_map
+            .get(&exception_id)
+            .expect("should exist exception in object_map")
+            .class_name_id;
+        for item in code.exception_table.iter() {
+            if !item.is_in_range(index) {
+                continue;
+            }
+            // catch_type 0 is used for finally
+            if item.catch_type == 0
+                || self.is_subclass_of(
+                    string_map,
+                    exception_class_name,
+                    class_file
+                        .cp_info
+                        .get_class_ref_name(item.catch_type as usize),
+                )
+            {
+                return Some(item.handler_pc as usize);
+            }
+        }
+        None
+    }
+
+    // the class itself is also regarded as its subclass
+    fn is_subclass_of(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: usize,
+        target_class_name: usize,
+    ) -> bool {
+        if class_name == target_class_name
+            || string_map.get_value(&target_class_name) == "java/lang/Object"
+        {
+            return true;
+        }
+        let summary = self.get_class_summary(string_map, class_name);
+        summary
+            .super_class_name
+            .into_iter()
+            .chain(summary.interface_names)
+            .any(|name| self.is_subclass_of(string_map, name, target_class_name))
+    }
+
+    // the class file is read only for its summary if it is not loaded yet
+    fn get_class_summary(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: usize,
+    ) -> ClassSummary {
+        if let Some(summary) = self.class_summaries.get(&class_name) {
+            return summary.clone();
+        }
+        let class_path =
+            Path::new(self.root_path).join(string_map.get_value(&class_name) + ".class");
+        let summary = if has_file(class_path.to_str().unwrap()) {
+            let buffer = get_file_content(class_path.to_str().unwrap());
+            let (class_file, _pc_count) = Custom::new(string_map, &buffer, 0);
+            ClassSummary::new(&class_file)
+        } else {
+            ClassSummary {
+                super_class_name: None,
+                interface_names: vec![],
+                methods: vec![],
+                instance_fields: vec![],
+                static_fields: vec![],
+            }
+        };
+        self.class_summaries.insert(class_name, summary.clone());
+        summary
+    }
+
+    // fields of the super classes are also laid out
+    fn create_field_map(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: usize,
+    ) -> HashMap<(usize, usize), (Item, Item)> {
+        let mut field_map = HashMap::new();
+        let mut current_class_name = Some(class_name);
+        while let Some(class_name) = current_class_name {
+            let summary = self.get_class_summary(string_map, class_name);
+            for (field_name, descriptor) in summary.instance_fields.iter() {
+                let descriptor = string_map.get_value(descriptor);
+                let vals = create_uninitialized_item(&FieldDescriptor::from(descriptor.as_ref()));
+                field_map.insert((class_name, *field_name), vals);
+            }
+            current_class_name = summary.super_class_name;
+        }
+        field_map
+    }
+
+    // returns the class which declares the field, looking up the super classes
+    fn resolve_field_class(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: usize,
+        field_name: usize,
+    ) -> usize {
+        let mut current_class_name = Some(class_name);
+        while let Some(current) = current_class_name {
+            let summary = self.get_class_summary(string_map, current);
+            if summary
+                .instance_fields
+                .iter()
+                .any(|(name, _)| *name == field_name)
+            {
+                return current;
+            }
+            current_class_name = summary.super_class_name;
+        }
+        class_name
+    }
+
+    // the class itself, its super interfaces and then its super class (JVMS 5.4.3.2)
+    fn find_static_field_class(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: usize,
+        field_name: usize,
+    ) -> Option<usize> {
+        let summary = self.get_class_summary(string_map, class_name);
+        if summary.static_fields.contains(&field_name) {
+            return Some(class_name);
+        }
+        for interface_name in summary.interface_names.iter() {
+            if let Some(found) =
+                self.find_static_field_class(string_map, *interface_name, field_name)
+            {
+                return Some(found);
+            }
+        }
+        summary.super_class_name.and_then(|super_class_name| {
+            self.find_static_field_class(string_map, super_class_name, field_name)
+        })
+    }
+
+    // static fields of builtin classes are not summarized
+    fn resolve_static_field_class(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: usize,
+        field_name: usize,
+    ) -> usize {
+        self.find_static_field_class(string_map, class_name, field_name)
+            .unwrap_or(class_name)
+    }
+
+    fn has_method(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: usize,
+        method_name: usize,
+        method_descriptor: usize,
+    ) -> bool {
+        if let Some(JavaClass::BuiltIn(builtin)) = self.class_map.get(&class_name) {
+            return builtin.methods.contains_key(&method_name);
+        }
+        self.get_class_summary(string_map, class_name)
+            .methods
+            .contains(&(method_name, method_descriptor))
+    }
+
+    // looks up the super classes first, and then the default methods of the super interfaces
+    fn find_method_class(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: usize,
+        method_name: usize,
+        method_descriptor: usize,
+    ) -> Option<usize> {
+        let key = (method_name, method_descriptor);
+        if let Some(method_class_name) = self
+            .vtables
+            .get(&class_name)
+            .and_then(|vtable| vtable.get(&key))
+        {
+            return Some(*method_class_name);
+        }
+        let method_class_name =
+            self.lookup_method_class(string_map, class_name, method_name, method_descriptor)?;
+        self.vtables
+            .entry(class_name)
+            .or_default()
+            .insert(key, method_class_name);
+        Some(method_class_name)
+    }
+
+    fn lookup_method_class(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: usize,
+        method_name: usize,
+        method_descriptor: usize,
+    ) -> Option<usize> {
+        let mut interface_names = vec![];
+        let mut current_class_name = Some(class_name);
+        while let Some(class_name) = current_class_name {
+            if self.has_method(string_map, class_name, method_name, method_descriptor) {
+                return Some(class_name);
+            }
+            let summary = self.get_class_summary(string_map, class_name);
+            interface_names.extend(summary.interface_names);
+            current_class_name = summary.super_class_name;
+        }
+
+        let mut index = 0;
+        while let Some(interface_name) = interface_names.get(index).cloned() {
+            if self.has_method(string_map, interface_name, method_name, method_descriptor) {
+                return Some(interface_name);
+            }
+            interface_names.extend(
+                self.get_class_summary(string_map, interface_name)
+                    .interface_names,
+            );
+            index += 1;
+        }
+        None
+    }
+
+    // names are the form of CONSTANT_Class like "Foo", "[I" or "[LFoo;"
+    fn is_assignable(&mut self, string_map: &mut StringPool, name: &str, target: &str) -> bool {
+        match (name.strip_prefix('['), target.strip_prefix('[')) {
+            (Some(component), Some(target_component)) => {
+                match (
+                    component_class_name(component),
+                    component_class_name(target_component),
+                ) {
+                    (Some(component), Some(target_component)) => {
+                        self.is_assignable(string_map, component, target_component)
+                    }
+                    _ => component == target_component,
+                }
+            }
+            (Some(_), None) => matches!(
+                target,
+                "java/lang/Object" | "java/lang/Cloneable" | "java/io/Serializable"
+            ),
+            (None, Some(_)) => false,
+            (None, None) => {
+                let class_name = string_map.insert(name.to_string());
+                let target_class_name = string_map.insert(target.to_string());
+                self.is_subclass_of(string_map, class_name, target_class_name)
+            }
+        }
+    }
+
+    fn get_runtime_class_name(&self, string_map: &mut StringPool, item: &Item) -> String {
+        match item {
+            Item::Objectref(id) => string_map.get_value(
+                &self
+                    .object_map
+                    .get(id)
+                    .expect("should exist item in object_map")
+                    .class_name_id,
+            ),
+            Item::Arrayref(id) => string_map.get_value(&self.array_map.get_class_name(id)),
+            Item::String(_) => String::from("java/lang/String"),
+            item => unreachable!("should be reference. actual: {:?}", item),
+        }
+    }
+
+    // returns whether the method finishes and the index of the next instruction
     pub fn execute(
         &mut self,
         string_map: &mut StringPool,
@@ -112,6 +587,15 @@ impl<'a> Context<'a> {
             };
         }
 
+        macro_rules! pair_culc {
+            ($method_name:ident) => {
+                let stackframe = self.get_last_stackframe();
+                let (first, second) = stackframe.operand_stack.$method_name();
+                stackframe.operand_stack.stack.push(first);
+                stackframe.operand_stack.stack.push(second);
+            };
+        }
+
         match instruction {
             Instruction::Aconstnull => {
                 let operand_stack = self.get_operand_stack();
@@ -136,12 +620,18 @@ impl<'a> Context<'a> {
                 single_culc!(fmul);
             }
             Instruction::Idiv => {
+                if !self.check_divisor(string_map, 1) {
+                    return (false, index);
+                }
                 single_culc!(idiv);
             }
             Instruction::Fdiv => {
                 single_culc!(fdiv);
             }
             Instruction::Irem => {
+                if !self.check_divisor(string_map, 1) {
+                    return (false, index);
+                }
                 single_culc!(irem);
             }
             Instruction::Ladd => {
@@ -163,25 +653,98 @@ impl<'a> Context<'a> {
                 stackframe.operand_stack.stack.push(second);
             }
             Instruction::Ldiv => {
+                if !self.check_divisor(string_map, 2) {
+                    return (false, index);
+                }
                 let stackframe = self.get_last_stackframe();
                 let (first, second) = stackframe.operand_stack.ldiv();
                 stackframe.operand_stack.stack.push(first);
                 stackframe.operand_stack.stack.push(second);
             }
             Instruction::Lrem => {
+                if !self.check_divisor(string_map, 2) {
+                    return (false, index);
+                }
                 let stackframe = self.get_last_stackframe();
                 let (first, second) = stackframe.operand_stack.lrem();
                 stackframe.operand_stack.stack.push(first);
                 stackframe.operand_stack.stack.push(second);
             }
+            Instruction::Dadd => {
+                pair_culc!(dadd);
+            }
+            Instruction::Dsub => {
+                pair_culc!(dsub);
+            }
+            Instruction::Dmul => {
+                pair_culc!(dmul);
+            }
+            Instruction::Ddiv => {
+                pair_culc!(ddiv);
+            }
+            Instruction::Frem => {
+                single_culc!(frem);
+            }
+            Instruction::Drem => {
+                pair_culc!(drem);
+            }
+            Instruction::Ineg => {
+                single_culc!(ineg);
+            }
+            Instruction::Lneg => {
+                pair_culc!(lneg);
+            }
+            Instruction::Fneg => {
+                single_culc!(fneg);
+            }
+            Instruction::Dneg => {
+                pair_culc!(dneg);
+            }
+            Instruction::Ishl => {
+                single_culc!(ishl);
+            }
+            Instruction::Lshl => {
+                pair_culc!(lshl);
+            }
+            Instruction::Ishr => {
+                single_culc!(ishr);
+            }
+            Instruction::Lshr => {
+                pair_culc!(lshr);
+            }
+            Instruction::Iushr => {
+                single_culc!(iushr);
+            }
+            Instruction::Lushr => {
+                pair_culc!(lushr);
+            }
+            Instruction::Iand => {
+                single_culc!(iand);
+            }
+            Instruction::Land => {
+                pair_culc!(land);
+            }
+            Instruction::Ior => {
+                single_culc!(ior);
+            }
+            Instruction::Lor => {
+                pair_culc!(lor);
+            }
+            Instruction::Ixor => {
+                single_culc!(ixor);
+            }
+            Instruction::Lxor => {
+                pair_culc!(lxor);
+            }
             Instruction::IconstN(val) => {
                 let operand_stack = self.get_operand_stack();
                 operand_stack.push(Item::Int(*val as i32));
             }
             Instruction::LconstN(val) => {
                 let operand_stack = self.get_operand_stack();
-                operand_stack.push(Item::Long(0));
-                operand_stack.push(Item::Long(*val));
+                let (first, second) = create_long_items(*val as i64);
+                operand_stack.push(first);
+                operand_stack.push(second);
             }
             Instruction::FconstN(val) => {
                 let operand_stack = self.get_operand_stack();
@@ -189,13 +752,9 @@ impl<'a> Context<'a> {
             }
             Instruction::DconstN(val) => {
                 let operand_stack = self.get_operand_stack();
-                let second = match val {
-                    0 => 0,
-                    1 => 0x3FF00000,
-                    _ => unreachable!(),
-                };
-                operand_stack.push(Item::Double(0));
-                operand_stack.push(Item::Double(second));
+                let (first, second) = create_double_items(*val as f64);
+                operand_stack.push(first);
+                operand_stack.push(second);
             }
             // maybe need to fix for float or something like that
             Instruction::Bipush(val) => {
@@ -206,20 +765,27 @@ impl<'a> Context<'a> {
                 let operand_stack = self.get_operand_stack();
                 operand_stack.push(Item::Int(*val as i32));
             }
-            Instruction::Lookupswitch(vals) => {
+            Instruction::Lookupswitch(default, pairs) => {
                 let operand_stack = self.get_operand_stack();
                 if let Some(Item::Int(target_key)) = operand_stack.pop() {
-                    if let Some(jump_pointer) = vals.iter().find(|(optional_key, _)| {
-                        if let Some(key) = *optional_key {
-                            key == target_key as usize
-                        } else {
-                            false
-                        }
-                    }) {
-                        return (false, jump_pointer.1);
+                    let jump_pointer = match pairs.iter().find(|(key, _)| *key == target_key) {
+                        Some((_, jump_pointer)) => *jump_pointer,
+                        None => *default,
+                    };
+                    return (false, jump_pointer);
+                } else {
+                    unreachable!("should exist operan_item");
+                }
+            }
+            Instruction::Tableswitch(low, high, default, jump_pointers) => {
+                let operand_stack = self.get_operand_stack();
+                if let Some(Item::Int(target_key)) = operand_stack.pop() {
+                    let jump_pointer = if *low <= target_key && target_key <= *high {
+                        jump_pointers[(target_key - low) as usize]
                     } else {
-                        return (false, vals.first().expect("should exist default value").1);
-                    }
+                        *default
+                    };
+                    return (false, jump_pointer);
                 } else {
                     unreachable!("should exist operan_item");
                 }
@@ -227,14 +793,80 @@ impl<'a> Context<'a> {
             Instruction::Goto(pointer) => {
                 return (false, *pointer);
             }
+            Instruction::Jsr(pointer) | Instruction::JsrW(pointer) => {
+                // the return address is the next instruction of jsr
+                let return_address = index + instruction.counsume_index() + 1;
+                let operand_stack = self.get_operand_stack();
+                operand_stack.push(Item::ReturnAddress(return_address));
+                return (false, *pointer);
+            }
+            Instruction::Ret(local_index) => {
+                let stackframe = self.get_last_stackframe();
+                return match stackframe.local_variables.get(*local_index) {
+                    Some(Item::ReturnAddress(address)) => (false, *address),
+                    item => unreachable!("should be ReturnAddress. actual: {:?}", item),
+                };
+            }
+            Instruction::GotoW(pointer) => {
+                return (false, *pointer);
+            }
+            Instruction::Wide(widened_instruction) => {
+                let result = self.execute(string_map, class_file, widened_instruction, index);
+                if let Instruction::Ret(_) = **widened_instruction {
+                    return result;
+                }
+            }
             Instruction::Iinc(index, value) => {
                 let stackframe = self.get_last_stackframe();
-                if let Some(item) = stackframe.local_variables.get_mut(*index) {
-                    if let Item::Int(val) = item {
-                        mem::replace(val, *val + *value as i32);
-                    }
+                if let Some(Item::Int(val)) = stackframe.local_variables.get_mut(*index) {
+                    *val = val.wrapping_add(*value);
                 }
             }
+            Instruction::I2l => {
+                pair_culc!(i2l);
+            }
+            Instruction::I2f => {
+                single_culc!(i2f);
+            }
+            Instruction::I2d => {
+                pair_culc!(i2d);
+            }
+            Instruction::L2i => {
+                single_culc!(l2i);
+            }
+            Instruction::L2f => {
+                single_culc!(l2f);
+            }
+            Instruction::L2d => {
+                pair_culc!(l2d);
+            }
+            Instruction::F2i => {
+                single_culc!(f2i);
+            }
+            Instruction::F2l => {
+                pair_culc!(f2l);
+            }
+            Instruction::F2d => {
+                pair_culc!(f2d);
+            }
+            Instruction::D2i => {
+                single_culc!(d2i);
+            }
+            Instruction::D2l => {
+                pair_culc!(d2l);
+            }
+            Instruction::D2f => {
+                single_culc!(d2f);
+            }
+            Instruction::I2b => {
+                single_culc!(i2b);
+            }
+            Instruction::I2c => {
+                single_culc!(i2c);
+            }
+            Instruction::I2s => {
+                single_culc!(i2s);
+            }
             Instruction::Lcmp => {
                 let stackframe = self.get_last_stackframe();
                 let val = stackframe.operand_stack.lcmp();
@@ -247,6 +879,12 @@ impl<'a> Context<'a> {
             Instruction::Fcmpg => {
                 single_culc!(fcmp);
             }
+            Instruction::Dcmpl => {
+                single_culc!(dcmpl);
+            }
+            Instruction::Dcmpg => {
+                single_culc!(dcmpg);
+            }
             Instruction::Ifeq(if_val, else_val) => {
                 let operand_stack = self.get_operand_stack();
                 let val = operand_stack.pop().unwrap();
@@ -349,9 +987,59 @@ impl<'a> Context<'a> {
                 let jump_pointer = if first <= second { *if_val } else { *else_val };
                 return (false, jump_pointer);
             }
+            Instruction::Ifacmpeq(if_val, else_val) => {
+                let operand_stack = self.get_operand_stack();
+                let second = operand_stack.pop().unwrap();
+                let first = operand_stack.pop().unwrap();
+                let jump_pointer = if first.is_same_reference(&second) {
+                    *if_val
+                } else {
+                    *else_val
+                };
+                return (false, jump_pointer);
+            }
+            Instruction::Ifacmpne(if_val, else_val) => {
+                let operand_stack = self.get_operand_stack();
+                let second = operand_stack.pop().unwrap();
+                let first = operand_stack.pop().unwrap();
+                let jump_pointer = if !first.is_same_reference(&second) {
+                    *if_val
+                } else {
+                    *else_val
+                };
+                return (false, jump_pointer);
+            }
+            Instruction::Ifnull(if_val, else_val) => {
+                let operand_stack = self.get_operand_stack();
+                let val = operand_stack.pop().unwrap();
+                let jump_pointer = if val == Item::Null {
+                    *if_val
+                } else {
+                    *else_val
+                };
+                return (false, jump_pointer);
+            }
+            Instruction::Ifnonnull(if_val, else_val) => {
+                let operand_stack = self.get_operand_stack();
+                let val = operand_stack.pop().unwrap();
+                let jump_pointer = if val != Item::Null {
+                    *if_val
+                } else {
+                    *else_val
+                };
+                return (false, jump_pointer);
+            }
             Instruction::Iload(index) => {
                 self.load_n(*index);
             }
+            Instruction::Lload(index) | Instruction::Dload(index) => {
+                let base_index = *index;
+                self.load_n(base_index);
+                self.load_n(base_index + 1);
+            }
+            Instruction::Fload(index) => {
+                self.load_n(*index);
+            }
             Instruction::Aload(index) => {
                 self.load_n(*index);
             }
@@ -377,6 +1065,13 @@ impl<'a> Context<'a> {
             Instruction::IstoreN(index) => {
                 self.store_n(&[*index as usize]);
             }
+            Instruction::Lstore(index) | Instruction::Dstore(index) => {
+                let base_index = *index;
+                self.store_n(&[base_index + 1, base_index]);
+            }
+            Instruction::Fstore(index) => {
+                self.store_n(&[*index]);
+            }
             Instruction::LstoreN(index) => {
                 let base_index = *index;
                 self.store_n(&[base_index + 1, base_index]);
@@ -392,37 +1087,51 @@ impl<'a> Context<'a> {
                 self.load_n(*index);
             }
             Instruction::Iaload => {
-                self.n_aload();
+                self.n_aload(string_map);
             }
             Instruction::Laload => {
-                self.n_aload();
+                self.n_aload(string_map);
+            }
+            Instruction::Faload => {
+                self.n_aload(string_map);
+            }
+            Instruction::Daload => {
+                self.n_aload(string_map);
             }
             Instruction::Baload => {
-                self.n_aload();
+                self.n_aload(string_map);
+            }
+            Instruction::Caload => {
+                self.n_aload(string_map);
+            }
+            Instruction::Saload => {
+                self.n_aload(string_map);
             }
             Instruction::Aaload => {
                 let operand_stack = self.get_operand_stack();
                 match (operand_stack.pop(), operand_stack.pop()) {
-                    (Some(Item::Int(index)), Some(Item::Arrayref(array_ref_id))) => {
+                    (Some(Item::Int(array_index)), Some(Item::Arrayref(array_ref_id))) => {
+                        if !self.check_array_index(string_map, array_ref_id, array_index) {
+                            return (false, index);
+                        }
                         let array_cell = self
                             .array_map
                             .get_mut(&array_ref_id)
                             .expect("should exist item in array_map");
 
                         let item = match array_cell {
-                            Array::Custom(items) => {
-                                let object_id = items.borrow()[index as usize];
-                                Item::Objectref(object_id)
-                            }
-                            Array::Array(ids) => {
-                                let array_id = ids.borrow()[index as usize];
-                                Item::Arrayref(array_id)
-                            }
+                            Array::Custom(items) => items.borrow()[array_index as usize]
+                                .map_or(Item::Null, Item::Objectref),
+                            Array::Nested(ids) => ids.borrow()[array_index as usize]
+                                .map_or(Item::Null, Item::Arrayref),
                             _ => unreachable!("Aaload doesn't handle Array::Primitive"),
                         };
                         let operand_stack = self.get_operand_stack();
                         operand_stack.push(item);
                     }
+                    (Some(Item::Int(_)), Some(Item::Null)) => {
+                        self.throw_null_pointer_exception(string_map);
+                    }
                     _ => unreachable!("should exist two items in operand_stack"),
                 };
             }
@@ -430,13 +1139,28 @@ impl<'a> Context<'a> {
                 self.store_n(&[*index]);
             }
             Instruction::Iastore => {
-                self.x_astore();
+                self.x_astore(string_map);
             }
             Instruction::Lastore => {
-                self.x_astore();
+                self.x_astore(string_map);
+            }
+            Instruction::Fastore => {
+                self.x_astore(string_map);
+            }
+            Instruction::Dastore => {
+                self.x_astore(string_map);
             }
             Instruction::Bastore => {
-                self.x_astore();
+                self.truncate_int_value(|val| val as i8 as i32);
+                self.x_astore(string_map);
+            }
+            Instruction::Castore => {
+                self.truncate_int_value(|val| val as u16 as i32);
+                self.x_astore(string_map);
+            }
+            Instruction::Sastore => {
+                self.truncate_int_value(|val| val as i16 as i32);
+                self.x_astore(string_map);
             }
             Instruction::Aastore => {
                 let operand_stack = self.get_operand_stack();
@@ -445,21 +1169,36 @@ impl<'a> Context<'a> {
                     operand_stack.pop(),
                     operand_stack.pop(),
                 ) {
-                    (Some(item), Some(Item::Int(index)), Some(Item::Arrayref(array_ref_id))) => {
+                    (
+                        Some(item),
+                        Some(Item::Int(array_index)),
+                        Some(Item::Arrayref(array_ref_id)),
+                    ) => {
+                        if !self.check_array_index(string_map, array_ref_id, array_index) {
+                            return (false, index);
+                        }
                         if let Some(array_cell) = self.array_map.get_mut(&array_ref_id) {
                             match item {
                                 Item::Objectref(ref_id) => {
                                     match array_cell {
                                         Array::Custom(items) => {
-                                            items.borrow_mut()[index as usize] = ref_id;
+                                            items.borrow_mut()[array_index as usize] = Some(ref_id);
                                         }
                                         _ => unimplemented!(),
                                     };
                                 }
                                 Item::Arrayref(ref_id) => {
                                     match array_cell {
-                                        Array::Array(items) => {
-                                            items.borrow_mut()[index as usize] = ref_id;
+                                        Array::Nested(items) => {
+                                            items.borrow_mut()[array_index as usize] = Some(ref_id);
+                                        }
+                                        _ => unimplemented!(),
+                                    };
+                                }
+                                Item::Null => {
+                                    match array_cell {
+                                        Array::Custom(items) | Array::Nested(items) => {
+                                            items.borrow_mut()[array_index as usize] = None;
                                         }
                                         _ => unimplemented!(),
                                     };
@@ -468,6 +1207,9 @@ impl<'a> Context<'a> {
                             };
                         }
                     }
+                    (Some(_), Some(Item::Int(_)), Some(Item::Null)) => {
+                        self.throw_null_pointer_exception(string_map);
+                    }
                     items @ _ => panic!(
                         "should exist three items in operand_stack
 ${:?}",
@@ -478,26 +1220,36 @@ ${:?}",
             Instruction::AstoreN(index) => {
                 self.store_n(&[*index]);
             }
-            Instruction::Putstatic(index) => {
-                let this_class_name = class_file.this_class_name();
-                let (class_name, field_name) = self.get_class_and_field_name(class_file, *index);
-                self.initilize_class_static_info(string_map, this_class_name, class_name);
+            Instruction::Putstatic(field_index) => {
+                let (class_name, field_name) =
+                    self.get_class_and_field_name(class_file, *field_index);
+                let class_name =
+                    self.resolve_static_field_class(string_map, class_name, field_name);
+                if !self.initialize_class(string_map, class_name) {
+                    return (false, index);
+                }
 
                 let operand_stack = self.get_operand_stack();
                 let (first, second) = match operand_stack.pop() {
-                    Some(second @ Item::Long(_)) => {
+                    Some(second @ Item::Long(_)) | Some(second @ Item::Double(_)) => {
                         let first = operand_stack.pop().unwrap();
                         (first, second)
                     }
                     first @ _ => (first.unwrap(), Item::Null),
                 };
                 self.static_fields
-                    .insert((class_name, field_name), (first, second));
-            }
-            Instruction::Getstatic(index) => {
-                let this_class_name = class_file.this_class_name();
-                let (class_name, field_name) = self.get_class_and_field_name(class_file, *index);
-                self.initilize_class_static_info(string_map, this_class_name, class_name);
+                    .entry(class_name)
+                    .or_default()
+                    .insert(field_name, (first, second));
+            }
+            Instruction::Getstatic(field_index) => {
+                let (class_name, field_name) =
+                    self.get_class_and_field_name(class_file, *field_index);
+                let class_name =
+                    self.resolve_static_field_class(string_map, class_name, field_name);
+                if !self.initialize_class(string_map, class_name) {
+                    return (false, index);
+                }
 
                 let err_message = format!(
                     "Getstatic failed. {}.{} is not found",
@@ -505,39 +1257,60 @@ ${:?}",
                 );
                 let items = self
                     .static_fields
-                    .get_mut(&(class_name, field_name))
+                    .get(&class_name)
+                    .and_then(|fields| fields.get(&field_name))
                     .expect(&err_message)
                     .clone();
 
                 let operand_stack = self.get_operand_stack();
                 match items.0 {
-                    Item::Long(_) => {
+                    Item::Long(_) | Item::Double(_) => {
                         operand_stack.push(items.0);
                         operand_stack.push(items.1);
                     }
                     _ => operand_stack.push(items.0),
                 };
             }
-            Instruction::Areturn | Instruction::Ireturn => {
-                let operand_stack = self.get_operand_stack();
-                let item = if let Some(item) = operand_stack.pop() {
-                    operand_stack.clear();
-                    item
-                } else {
-                    unreachable!("should exist return value on operand_stack")
-                };
-                let length = self.stack_frames.len();
-                if let Some(stackframe) = self.stack_frames.get_mut(length - 2) {
-                    stackframe.operand_stack.stack.push(item);
-                } else {
-                    unreachable!("should exist over two stack_frame");
-                }
+            Instruction::Areturn | Instruction::Ireturn | Instruction::Freturn => {
+                self.return_value(1);
+                return (true, index);
+            }
+            Instruction::Lreturn | Instruction::Dreturn => {
+                self.return_value(2);
                 return (true, index);
             }
             Instruction::Pop => {
                 let operand_stack = self.get_operand_stack();
                 operand_stack.pop();
             }
+            Instruction::Pop2 => {
+                let stackframe = self.get_last_stackframe();
+                stackframe.operand_stack.pop2();
+            }
+            Instruction::DupX1 => {
+                let stackframe = self.get_last_stackframe();
+                stackframe.operand_stack.dup_x1();
+            }
+            Instruction::DupX2 => {
+                let stackframe = self.get_last_stackframe();
+                stackframe.operand_stack.dup_x2();
+            }
+            Instruction::Dup2 => {
+                let stackframe = self.get_last_stackframe();
+                stackframe.operand_stack.dup2();
+            }
+            Instruction::Dup2X1 => {
+                let stackframe = self.get_last_stackframe();
+                stackframe.operand_stack.dup2_x1();
+            }
+            Instruction::Dup2X2 => {
+                let stackframe = self.get_last_stackframe();
+                stackframe.operand_stack.dup2_x2();
+            }
+            Instruction::Swap => {
+                let stackframe = self.get_last_stackframe();
+                stackframe.operand_stack.swap();
+            }
             Instruction::Dup => {
                 let operand_stack = self.get_operand_stack();
                 let last = if let Some(last) = operand_stack.last() {
@@ -547,51 +1320,122 @@ ${:?}",
                 };
                 operand_stack.push(last);
             }
-            Instruction::Invokevirtual(index) | Instruction::Invokespecial(index) => {
-                let (class_name, name_and_type) = self.get_related_method_info(class_file, *index);
-                self.call_method(string_map, &class_file, class_name, name_and_type);
+            Instruction::Invokevirtual(method_index) => {
+                let (class_name, name_and_type) =
+                    self.get_related_method_info(class_file, *method_index);
+                let method_name = class_file.cp_info.get_utf8(name_and_type.name_index);
+                let method_descriptor = class_file.cp_info.get_utf8(name_and_type.descriptor_index);
+                let parameter_length =
+                    parameter_slot_length(&string_map.get_value(&method_descriptor));
+                let operand_stack = self.get_operand_stack();
+                let objectref = operand_stack
+                    .get(operand_stack.len() - parameter_length - 1)
+                    .cloned()
+                    .expect("should exist objectref in operand_stack");
+                // the method is selected from the runtime class of objectref
+                let runtime_class_name = match objectref {
+                    Item::Null => {
+                        self.throw_null_pointer_exception(string_map);
+                        return (false, index);
+                    }
+                    Item::Objectref(_) => {
+                        let runtime_class_name =
+                            self.get_runtime_class_name(string_map, &objectref);
+                        string_map.insert(runtime_class_name)
+                    }
+                    Item::Arrayref(_) => string_map.insert(String::from("java/lang/Object")),
+                    // builtin instances like System.out
+                    _ => class_name,
+                };
+                let method_class_name = self
+                    .find_method_class(
+                        string_map,
+                        runtime_class_name,
+                        method_name,
+                        method_descriptor,
+                    )
+                    .unwrap_or(class_name);
+                self.call_method(string_map, class_file, method_class_name, name_and_type);
+            }
+            // the method is not selected by objectref. super.method() refers the direct super class
+            Instruction::Invokespecial(method_index) => {
+                let (class_name, name_and_type) =
+                    self.get_related_method_info(class_file, *method_index);
+                let method_class_name =
+                    self.resolve_method_class(string_map, class_file, class_name, name_and_type);
+                self.call_method(string_map, class_file, method_class_name, name_and_type);
+            }
+            Instruction::Invokestatic(method_index) => {
+                let (class_name, name_and_type) =
+                    self.get_related_method_info(class_file, *method_index);
+                let method_class_name =
+                    self.resolve_method_class(string_map, class_file, class_name, name_and_type);
+                if !self.initialize_class(string_map, method_class_name) {
+                    return (false, index);
+                }
+                self.call_method(string_map, class_file, method_class_name, name_and_type);
             }
-            Instruction::Invokestatic(index) => {
-                let this_class_name = class_file.this_class_name();
-                let (class_name, name_and_type) = self.get_related_method_info(class_file, *index);
-                self.initilize_class_static_info(string_map, this_class_name, class_name);
-                self.call_method(string_map, &class_file, class_name, name_and_type);
+            Instruction::Invokeinterface(method_index, count) => {
+                let (_, name_and_type) = self.get_related_method_info(class_file, *method_index);
+                let operand_stack = self.get_operand_stack();
+                // count includes the objectref
+                let objectref = operand_stack
+                    .get(operand_stack.len() - count)
+                    .cloned()
+                    .expect("should exist objectref in operand_stack");
+                if let Item::Null = objectref {
+                    self.throw_null_pointer_exception(string_map);
+                    return (false, index);
+                }
+                let runtime_class_name = self.get_runtime_class_name(string_map, &objectref);
+                let runtime_class_name = string_map.insert(runtime_class_name);
+                let method_name = class_file.cp_info.get_utf8(name_and_type.name_index);
+                let method_descriptor = class_file.cp_info.get_utf8(name_and_type.descriptor_index);
+                let class_name = self
+                    .find_method_class(
+                        string_map,
+                        runtime_class_name,
+                        method_name,
+                        method_descriptor,
+                    )
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "{}{} is not implemented in {}",
+                            string_map.get_value(&method_name),
+                            string_map.get_value(&method_descriptor),
+                            string_map.get_value(&runtime_class_name)
+                        )
+                    });
+                self.call_method(string_map, class_file, class_name, name_and_type);
             }
             Instruction::Putfield(index) => {
                 let (class_name, field_name) = class_file.cp_info.get_class_and_field_name(*index);
+                let class_name = self.resolve_field_class(string_map, class_name, field_name);
                 let vals = self.get_field_tupple();
 
                 let item = self
                     .get_operand_stack()
                     .pop()
                     .expect("should exist item in operand_stack");
-                let obj_id = match item {
+                match item {
                     Item::Objectref(obj_id) => {
                         let obj_ref = self
                             .object_map
                             .get_mut(&obj_id)
                             .expect("should exist object_ref in object_map");
-
-                        {
-                            assert!(
-                                class_name == obj_ref.class_name_id,
-                                "should be equal class_name"
-                            );
-                        }
-
                         obj_ref
                             .field_map
                             .borrow_mut()
-                            .insert((field_name, obj_id), vals);
-                        obj_id
+                            .insert((class_name, field_name), vals);
                     }
+                    Item::Null => self.throw_null_pointer_exception(string_map),
                     item @ _ => unreachable!("should be Objectref. actual: {}", item),
                 };
-                let operand_stack = self.get_operand_stack();
-                operand_stack.push(Item::Objectref(obj_id));
             }
-            Instruction::Getfield(index) => {
-                let (class_name, field_name) = class_file.cp_info.get_class_and_field_name(*index);
+            Instruction::Getfield(field_index) => {
+                let (class_name, field_name) =
+                    class_file.cp_info.get_class_and_field_name(*field_index);
+                let class_name = self.resolve_field_class(string_map, class_name, field_name);
 
                 let item = self
                     .get_operand_stack()
@@ -603,27 +1447,23 @@ ${:?}",
                             .object_map
                             .get(&obj_id)
                             .expect("should exist objectref in object_map");
-
-                        {
-                            assert!(
-                                class_name == obj_ref.class_name_id,
-                                "should be equal class_name"
-                            );
-                        }
-
                         let field_map = obj_ref.field_map.borrow();
                         let (first, second) = field_map
-                            .get(&(field_name, obj_id))
+                            .get(&(class_name, field_name))
                             .expect("should exist item")
                             .clone();
                         (first, second)
                     }
+                    Item::Null => {
+                        self.throw_null_pointer_exception(string_map);
+                        return (false, index);
+                    }
                     item @ _ => unreachable!("should be Objectref. actual: {}", item),
                 };
 
                 let operand_stack = self.get_operand_stack();
                 match first {
-                    Item::Long(_) => {
+                    Item::Long(_) | Item::Double(_) => {
                         operand_stack.push(first);
                         operand_stack.push(second.clone());
                     }
@@ -632,7 +1472,7 @@ ${:?}",
                     }
                 }
             }
-            Instruction::Ldc(index) => {
+            Instruction::Ldc(index) | Instruction::LdcW(index) => {
                 let index_value = *index;
                 let operand_stack = self.get_operand_stack();
                 match class_file.cp_info.get_item_tag(index_value) {
@@ -640,6 +1480,10 @@ ${:?}",
                         let val = class_file.cp_info.get_string(index_value);
                         operand_stack.push(Item::String(val));
                     }
+                    ConstPoolTag::ConstantInteger => {
+                        let val = class_file.cp_info.get_integer(index_value);
+                        operand_stack.push(Item::Int(val));
+                    }
                     ConstPoolTag::ConstantFloat => {
                         let val = class_file.cp_info.get_float(index_value);
                         operand_stack.push(Item::Float(val));
@@ -654,38 +1498,35 @@ ${:?}",
                     (*first << 8 | *second) & 0xFFFF,
                 );
             }
-            Instruction::New(index) => {
-                let this_class_name = class_file.this_class_name();
-                let class_ref = class_file.cp_info.get_class_ref(*index);
-                let class_name = class_file.cp_info.get_utf8(class_ref.name_index);
-                self.initilize_class_static_info(string_map, this_class_name, class_name);
+            Instruction::New(class_index) => {
+                let class_name = class_file.cp_info.get_class_ref_name(*class_index);
+                if !self.initialize_class(string_map, class_name) {
+                    return (false, index);
+                }
 
-                let object_ref = if let Some(JavaClass::Custom(target_class)) =
-                    self.class_map.get(&class_name)
-                {
-                    let mut field_map = HashMap::new();
-                    for (index, field) in target_class.fields.iter().enumerate() {
-                        let field_name = target_class.cp_info.get_utf8(field.name_index);
-                        let descriptor = target_class
-                            .cp_info
-                            .get_utf8_as_string(string_map, field.descriptor_index);
-                        let vals =
-                            create_uninitialized_item(&FieldDescriptor::from(descriptor.as_ref()));
-                        field_map.insert((field_name, index), vals);
-                    }
-                    Objectref::new(class_name, RefCell::new(field_map), true)
-                } else {
-                    unreachable!("not come here")
-                };
+                let object_ref =
+                    if let Some(JavaClass::BuiltIn(_)) = self.class_map.get(&class_name) {
+                        Objectref::new(class_name, RefCell::new(HashMap::new()), true)
+                    } else {
+                        let field_map = self.create_field_map(string_map, class_name);
+                        Objectref::new(class_name, RefCell::new(field_map), true)
+                    };
                 let id = self.object_map.add(object_ref);
                 let operand_stack = self.get_operand_stack();
                 operand_stack.push(Item::Objectref(id));
             }
             Instruction::Newarray(type_index) => {
                 let id = if let Some(Item::Int(length)) = self.get_operand_stack().pop() {
+                    if !self.check_array_length(string_map, length) {
+                        return (false, index);
+                    }
                     let default_array = iniailize_primitive_array(*type_index, length as usize);
-                    self.array_map
-                        .add(Array::Primitive(RefCell::new(default_array)))
+                    let array_class_name =
+                        string_map.insert(primitive_array_class_name(*type_index).to_string());
+                    self.array_map.add(
+                        Array::Primitive(RefCell::new(default_array)),
+                        array_class_name,
+                    )
                 } else {
                     unreachable!("should exist item in operand_stack")
                 };
@@ -694,16 +1535,22 @@ ${:?}",
                 operand_stack.push(Item::Arrayref(id));
             }
             // class, array, or interface type
-            Instruction::Anewarray(index) => {
-                let class_name = class_file.cp_info.get_class_ref_name(*index);
+            Instruction::Anewarray(class_index) => {
+                let class_name = class_file.cp_info.get_class_ref_name(*class_index);
                 let id = if let Some(Item::Int(length)) = self.get_operand_stack().pop() {
-                    let default_array = initialize_objectref_array(
-                        &mut self.object_map,
-                        class_name,
-                        length as usize,
-                    );
-                    self.array_map
-                        .add(Array::Custom(RefCell::new(default_array)))
+                    if !self.check_array_length(string_map, length) {
+                        return (false, index);
+                    }
+                    // the elements are null until they are stored
+                    let class_name = string_map.get_value(&class_name);
+                    let default_array = RefCell::new(vec![None; length as usize]);
+                    let array = if class_name.starts_with('[') {
+                        Array::Nested(default_array)
+                    } else {
+                        Array::Custom(default_array)
+                    };
+                    let array_class_name = string_map.insert(object_array_class_name(&class_name));
+                    self.array_map.add(array, array_class_name)
                 } else {
                     unreachable!("should exist item in operand_stack")
                 };
@@ -711,41 +1558,109 @@ ${:?}",
                 let operand_stack = self.get_operand_stack();
                 operand_stack.push(Item::Arrayref(id));
             }
+            Instruction::Arraylength => {
+                let length = match self.get_operand_stack().pop() {
+                    Some(Item::Arrayref(array_ref_id)) => self
+                        .array_map
+                        .get(&array_ref_id)
+                        .expect("should exist item in array_map")
+                        .len(),
+                    Some(Item::Null) => {
+                        self.throw_null_pointer_exception(string_map);
+                        return (false, index);
+                    }
+                    item => unreachable!("should be Arrayref. actual: {:?}", item),
+                };
+                let operand_stack = self.get_operand_stack();
+                operand_stack.push(Item::Int(length as i32));
+            }
             Instruction::Return => {
                 let operand_stack = self.get_operand_stack();
                 operand_stack.clear();
+                return (true, index);
+            }
+            Instruction::Athrow => match self.get_operand_stack().pop() {
+                Some(Item::Objectref(exception_id)) => {
+                    self.thrown_exception = Some(exception_id);
+                }
+                Some(Item::Null) => self.throw_null_pointer_exception(string_map),
+                item => unreachable!("should be Objectref. actual: {:?}", item),
+            },
+            Instruction::Checkcast(class_index) => {
+                let item = self
+                    .get_operand_stack()
+                    .last()
+                    .cloned()
+                    .expect("should exist item in operand_stack");
+                if let Item::Null = item {
+                    return (false, index + instruction.counsume_index() + 1);
+                }
+                let class_name = self.get_runtime_class_name(string_map, &item);
+                let target_class_name =
+                    string_map.get_value(&class_file.cp_info.get_class_ref_name(*class_index));
+                if !self.is_assignable(string_map, &class_name, &target_class_name) {
+                    let message = format!(
+                        "class {} cannot be cast to class {}",
+                        class_name.replace("/", "."),
+                        target_class_name.replace("/", ".")
+                    );
+                    self.throw_exception(string_map, "java/lang/ClassCastException", Some(message));
+                    return (false, index);
+                }
             }
-            Instruction::Multianewarray(index, dimentions) => {
+            Instruction::Instanceof(class_index) => {
+                let item = self
+                    .get_operand_stack()
+                    .pop()
+                    .expect("should exist item in operand_stack");
+                let result = if let Item::Null = item {
+                    false
+                } else {
+                    let class_name = self.get_runtime_class_name(string_map, &item);
+                    let target_class_name =
+                        string_map.get_value(&class_file.cp_info.get_class_ref_name(*class_index));
+                    self.is_assignable(string_map, &class_name, &target_class_name)
+                };
+                let operand_stack = self.get_operand_stack();
+                operand_stack.push(Item::Int(result as i32));
+            }
+            Instruction::Multianewarray(class_index, dimentions) => {
                 let operand_stack = self.get_operand_stack();
                 let operand_stack_len = operand_stack.len();
-                let mut counts: Vec<usize> = operand_stack
+                let counts: Vec<i32> = operand_stack
                     .drain(operand_stack_len - dimentions..operand_stack_len)
                     .map(|item| {
                         if let Item::Int(val) = item {
-                            val as usize
+                            val
                         } else {
                             unreachable!("Item should be int")
                         }
                     })
                     .collect();
+                if let Some(length) = counts.iter().find(|count| **count < 0) {
+                    self.check_array_length(string_map, *length);
+                    return (false, index);
+                }
+                let mut counts: Vec<usize> =
+                    counts.into_iter().map(|count| count as usize).collect();
 
                 let dimentions = *dimentions;
-                let class_array_name_id = class_file.cp_info.get_class_ref_name(*index);
+                let class_array_name_id = class_file.cp_info.get_class_ref_name(*class_index);
                 let class_array_name = string_map.get_value(&class_array_name_id);
                 let class_name = &class_array_name[dimentions..];
+                // class names of the arrays in each dimention
+                let array_class_names: Vec<usize> = (0..dimentions)
+                    .map(|dimention| string_map.insert(class_array_name[dimention..].to_string()))
+                    .collect();
                 match &class_name[0..1] {
                     // for class
                     "L" => {
-                        let class_name_len = class_name.len();
-                        let actual_class_name = &class_name[1..class_name_len - 1];
                         let first_count = counts.first().unwrap().clone();
-                        let string_id = string_map.insert(actual_class_name.to_string());
-
                         let multi_dimentions_id = self.create_multi_dimentions_custom_array(
                             &mut counts,
                             1, // default should be 1
                             first_count,
-                            string_id,
+                            &array_class_names,
                         );
                         let operand_stack = self.get_operand_stack();
                         operand_stack.push(Item::Arrayref(multi_dimentions_id));
@@ -759,6 +1674,7 @@ ${:?}",
                             1, // default should be 1
                             first_count,
                             initial_val,
+                            &array_class_names,
                         );
 
                         let operand_stack = self.get_operand_stack();
@@ -768,43 +1684,159 @@ ${:?}",
             }
             _ => {}
         };
-        (false, index + instruction.counsume_index())
+        (false, index + instruction.counsume_index() + 1)
     }
 
-    fn x_astore(&mut self) {
+    fn x_astore(&mut self, string_map: &mut StringPool) {
         let operand_stack = self.get_operand_stack();
         let value = operand_stack.pop().expect("should exist item");
         let values = match value {
-            item @ Item::Long(_) => (item, operand_stack.pop().expect("should exist item")),
+            item @ Item::Long(_) | item @ Item::Double(_) => {
+                (operand_stack.pop().expect("should exist item"), item)
+            }
             item @ _ => (item, Item::Null),
         };
 
         match (operand_stack.pop(), operand_stack.pop()) {
             (Some(Item::Int(index)), Some(Item::Arrayref(array_ref_id))) => {
+                if !self.check_array_index(string_map, array_ref_id, index) {
+                    return;
+                }
                 if let Some(array_cell) = self.array_map.get_mut(&array_ref_id) {
                     match array_cell {
                         Array::Primitive(items) => {
-                            items.borrow_mut()[index as usize] = values;
+                            let mut items = items.borrow_mut();
+                            // boolean arrays keep the value as boolean
+                            let values = match (&items[index as usize].0, values) {
+                                (Item::Boolean(_), (Item::Int(val), second)) => {
+                                    (Item::Boolean(val & 1 == 1), second)
+                                }
+                                (_, values) => values,
+                            };
+                            items[index as usize] = values;
                         }
-                        Array::Array(items) => {
+                        Array::Nested(items) => {
                             if let Item::Int(val) = values.0 {
-                                items.borrow_mut()[index as usize] = val as usize;
+                                items.borrow_mut()[index as usize] = Some(val as usize);
                             } else {
-                                unreachable!("should int to set Array::Array item")
+                                unreachable!("should int to set Array::Nested item")
                             }
                         }
                         _ => unimplemented!(),
                     };
                 }
             }
+            (Some(Item::Int(_)), Some(Item::Null)) => self.throw_null_pointer_exception(string_map),
             _ => unreachable!("should exist three items in operand_stack"),
         };
     }
 
-    fn n_aload(&mut self) {
+    // throws ArrayIndexOutOfBoundsException if the index is out of the array
+    fn check_array_index(
+        &mut self,
+        string_map: &mut StringPool,
+        array_ref_id: usize,
+        index: i32,
+    ) -> bool {
+        let length = self
+            .array_map
+            .get(&array_ref_id)
+            .expect("should exist item in array_map")
+            .len();
+        if 0 <= index && (index as usize) < length {
+            return true;
+        }
+        self.throw_exception(
+            string_map,
+            "java/lang/ArrayIndexOutOfBoundsException",
+            Some(format!(
+                "Index {} out of bounds for length {}",
+                index, length
+            )),
+        );
+        false
+    }
+
+    // throws ArithmeticException if the divisor on the top of operand_stack is zero.
+    // long divisor uses two items
+    fn check_divisor(&mut self, string_map: &mut StringPool, size: usize) -> bool {
+        let operand_stack = self.get_operand_stack();
+        let length = operand_stack.len();
+        let is_zero = operand_stack[length - size..]
+            .iter()
+            .all(|item| *item == Item::Int(0) || *item == Item::Long(0));
+        if is_zero {
+            self.throw_exception(
+                string_map,
+                "java/lang/ArithmeticException",
+                Some(String::from("/ by zero")),
+            );
+        }
+        !is_zero
+    }
+
+    // throws NegativeArraySizeException if the length is negative
+    fn check_array_length(&mut self, string_map: &mut StringPool, length: i32) -> bool {
+        if length < 0 {
+            self.throw_exception(
+                string_map,
+                "java/lang/NegativeArraySizeException",
+                Some(length.to_string()),
+            );
+        }
+        length >= 0
+    }
+
+    fn throw_null_pointer_exception(&mut self, string_map: &mut StringPool) {
+        self.throw_exception(string_map, "java/lang/NullPointerException", None);
+    }
+
+    fn throw_exception(
+        &mut self,
+        string_map: &mut StringPool,
+        class_name: &str,
+        message: Option<String>,
+    ) -> usize {
+        let class_name_id = string_map.insert(class_name.to_string());
+        let exception_id = self.object_map.add(Objectref::new(
+            class_name_id,
+            RefCell::new(HashMap::new()),
+            true,
+        ));
+        if let Some(message) = message {
+            let throwable_name = string_map.insert(String::from("java/lang/Throwable"));
+            let field_name = string_map.insert(String::from("detailMessage"));
+            let message_id = string_map.insert(message);
+            self.object_map
+                .get(&exception_id)
+                .expect("should exist exception in object_map")
+                .field_map
+                .borrow_mut()
+                .insert(
+                    (throwable_name, field_name),
+                    (Item::String(message_id), Item::Null),
+                );
+        }
+        self.thrown_exception = Some(exception_id);
+        exception_id
+    }
+
+    // byte, char and short values are truncated before stored
+    fn truncate_int_value(&mut self, truncate: fn(i32) -> i32) {
+        let operand_stack = self.get_operand_stack();
+        match operand_stack.pop() {
+            Some(Item::Int(val)) => operand_stack.push(Item::Int(truncate(val))),
+            item => unreachable!("should be Int. actual: {:?}", item),
+        };
+    }
+
+    fn n_aload(&mut self, string_map: &mut StringPool) {
         let operand_stack = self.get_operand_stack();
         match (operand_stack.pop(), operand_stack.pop()) {
             (Some(Item::Int(index)), Some(Item::Arrayref(array_ref_id))) => {
+                if !self.check_array_index(string_map, array_ref_id, index) {
+                    return;
+                }
                 let array_cell = self
                     .array_map
                     .get_mut(&array_ref_id)
@@ -814,12 +1846,16 @@ ${:?}",
                     _ => unimplemented!(),
                 };
                 let operand_stack = self.get_operand_stack();
-                operand_stack.push(item.0);
+                match item.0 {
+                    Item::Boolean(val) => operand_stack.push(Item::Int(val as i32)),
+                    first => operand_stack.push(first),
+                };
                 match item.1 {
                     Item::Null => {}
                     _ => operand_stack.push(item.1),
                 };
             }
+            (Some(Item::Int(_)), Some(Item::Null)) => self.throw_null_pointer_exception(string_map),
             _ => panic!("should exist two items in operand_stack"),
         };
     }
@@ -829,21 +1865,15 @@ ${:?}",
         counts: &mut Vec<usize>,
         current_index: usize,
         current_size: usize,
-        class_name_id: usize,
+        array_class_names: &[usize],
     ) -> usize {
         let next_size = if let Some(next_size) = counts.get(current_index) {
             next_size.clone()
         } else {
-            let mut items = Vec::with_capacity(current_size);
-            for _ in 0..current_size {
-                let id = self.object_map.add(Objectref::new(
-                    class_name_id,
-                    RefCell::new(HashMap::new()),
-                    false,
-                ));
-                items.push(id);
-            }
-            return self.array_map.add(Array::Custom(RefCell::new(items)));
+            return self.array_map.add(
+                Array::Custom(RefCell::new(vec![None; current_size])),
+                array_class_names[current_index - 1],
+            );
         };
 
         let mut ids = Vec::with_capacity(current_size);
@@ -852,12 +1882,15 @@ ${:?}",
                 counts,
                 current_index + 1,
                 next_size,
-                class_name_id,
+                array_class_names,
             );
-            ids.push(input_id);
+            ids.push(Some(input_id));
         }
 
-        self.array_map.add(Array::Array(RefCell::new(ids)))
+        self.array_map.add(
+            Array::Nested(RefCell::new(ids)),
+            array_class_names[current_index - 1],
+        )
     }
 
     fn create_multi_dimentions_array(
@@ -866,11 +1899,16 @@ ${:?}",
         current_index: usize,
         current_size: usize,
         initial_value: (Item, Item),
+        array_class_names: &[usize],
     ) -> usize {
         let next_size = if let Some(next_size) = counts.get(current_index) {
             next_size.clone()
         } else {
-            return self.create_leaf_child(current_size, initial_value);
+            return self.create_leaf_child(
+                current_size,
+                initial_value,
+                array_class_names[current_index - 1],
+            );
         };
 
         self.create_other_dimention(
@@ -879,15 +1917,22 @@ ${:?}",
             current_size,
             next_size,
             initial_value,
+            array_class_names,
         )
     }
 
-    fn create_leaf_child(&mut self, current_size: usize, initial_value: (Item, Item)) -> usize {
+    fn create_leaf_child(
+        &mut self,
+        current_size: usize,
+        initial_value: (Item, Item),
+        array_class_name: usize,
+    ) -> usize {
         let mut items = Vec::with_capacity(current_size);
         for _ in 0..current_size {
             items.push(initial_value.clone());
         }
-        self.array_map.add(Array::Primitive(RefCell::new(items)))
+        self.array_map
+            .add(Array::Primitive(RefCell::new(items)), array_class_name)
     }
 
     fn create_other_dimention(
@@ -897,6 +1942,7 @@ ${:?}",
         current_size: usize,
         next_size: usize,
         initial_value: (Item, Item),
+        array_class_names: &[usize],
     ) -> usize {
         let mut ids = Vec::with_capacity(current_size);
         for _ in 0..current_size {
@@ -905,24 +1951,49 @@ ${:?}",
                 current_index + 1,
                 next_size,
                 initial_value.clone(),
+                array_class_names,
             );
-            ids.push(input_id);
+            ids.push(Some(input_id));
+        }
+        self.array_map.add(
+            Array::Nested(RefCell::new(ids)),
+            array_class_names[current_index - 1],
+        )
+    }
+
+    // long and double values use two items
+    fn return_value(&mut self, size: usize) {
+        let operand_stack = self.get_operand_stack();
+        let operand_stack_len = operand_stack.len();
+        if operand_stack_len < size {
+            unreachable!("should exist return value on operand_stack")
+        }
+        let items: Vec<Item> = operand_stack
+            .drain(operand_stack_len - size..operand_stack_len)
+            .collect();
+        operand_stack.clear();
+        let length = self.stack_frames.len();
+        if let Some(stackframe) = self.stack_frames.get_mut(length - 2) {
+            stackframe.operand_stack.stack.extend(items);
+        } else {
+            unreachable!("should exist over two stack_frame");
         }
-        self.array_map.add(Array::Array(RefCell::new(ids)))
     }
 
     fn get_field_tupple(&mut self) -> (Item, Item) {
         let operand_stack = self.get_operand_stack();
-        let first = operand_stack
+        let last = operand_stack
             .pop()
             .expect("should exist operand stack item");
-        let second = match first {
-            Item::Long(_) => operand_stack
-                .pop()
-                .expect("should exist operand stack item"),
-            _ => Item::Null,
-        };
-        (first, second)
+        match last {
+            Item::Long(_) | Item::Double(_) => {
+                let first = operand_stack
+                    .pop()
+                    .expect("should exist operand stack item");
+                (first, last)
+            }
+            _ => (last, Item::Null),
+        }
     }
 
     fn get_last_stackframe(&mut self) -> &mut Stackframe {
@@ -970,33 +2041,16 @@ ${:?}",
         }
     }
 
-    fn initilize_class_static_info(
-        &mut self,
-        string_map: &mut StringPool,
-        this_class_name_id: usize,
-        class_name_id: usize,
-    ) {
-        let this_class_name = string_map.get_value(&this_class_name_id);
-        let class_name = string_map.get_value(&class_name_id);
-        if this_class_name != class_name && self.class_map.get_mut(&class_name_id).is_none() {
-            let new_class_file = self.create_custom_class(string_map, class_name_id);
-            if let Some(code) = new_class_file.get_clinit_code() {
-                self.call_custom_class_method(string_map, &new_class_file, code);
-            }
-
-            self.class_map
-                .insert(class_name_id, JavaClass::Custom(new_class_file));
-        }
-    }
-
     fn create_custom_class(&mut self, string_map: &mut StringPool, class_name: usize) -> Custom {
         let class_name = string_map.get_value(&class_name);
         let class_name = class_name + ".class";
         let class_path = Path::new(self.root_path).join(&class_name);
         let buffer = get_file_content(&class_path.to_str().unwrap());
         let (new_class_file, _pc_count) = Custom::new(string_map, &buffer, 0);
-        // TBD should be set initial value
-        set_static_fields(string_map, &new_class_file, &mut self.static_fields);
+        self.class_summaries.insert(
+            new_class_file.this_class_name(),
+            ClassSummary::new(&new_class_file),
+        );
         new_class_file
     }
 
@@ -1015,15 +2069,32 @@ ${:?}",
                     method_name, builtin_class.class_name
                 ));
                 let parameter_length = method.parameter_length(string_map, method_descriptor);
-                let stack_frame = self.create_new_stack_frame(parameter_length);
+                let stack_frame = self.create_new_stack_frame(parameter_length, parameter_length);
                 self.stack_frames.push(stack_frame);
-                method.execute(string_map, &caller_cp_info, &mut self.stack_frames);
+                method.execute(
+                    string_map,
+                    caller_cp_info,
+                    &mut self.stack_frames,
+                    &mut self.object_map,
+                );
             }
             JavaClass::Custom(ref custom_class) => {
                 if let Some(method_code) =
                     custom_class.get_method_code_by_string(method_name, method_descriptor)
                 {
-                    self.call_custom_class_method(string_map, custom_class, &method_code);
+                    let is_static = custom_class
+                        .get_method_by_string(method_name, method_descriptor)
+                        .is_some_and(|method| method.is_static());
+                    // the receiver uses the first slot of a non-static method
+                    let parameter_length =
+                        parameter_slot_length(&string_map.get_value(&method_descriptor))
+                            + if is_static { 0 } else { 1 };
+                    self.call_custom_class_method(
+                        string_map,
+                        custom_class,
+                        parameter_length,
+                        method_code,
+                    );
                 }
             }
         }
@@ -1033,10 +2104,11 @@ ${:?}",
         &mut self,
         string_map: &mut StringPool,
         class: &Custom,
+        parameter_length: usize,
         code: &Code,
     ) {
         let local_variable_length = code.max_locals as usize;
-        let stack_frame = self.create_new_stack_frame(local_variable_length);
+        let stack_frame = self.create_new_stack_frame(parameter_length, local_variable_length);
         self.stack_frames.push(stack_frame);
         self.run_method(string_map, class, code);
     }
@@ -1074,11 +2146,10 @@ ${:?}",
         });
 
         for (index, item) in item_vec.into_iter() {
-            if stackframe.local_variables.get(index).is_some() {
-                stackframe.local_variables[index] = Item::from(item);
-            } else {
-                stackframe.local_variables.insert(index, Item::from(item));
+            if stackframe.local_variables.len() <= index {
+                stackframe.local_variables.resize(index + 1, Item::Null);
             }
+            stackframe.local_variables[index] = item;
         }
     }
 
@@ -1100,33 +2171,49 @@ ${:?}",
         class_file: &'b Custom,
         index: usize,
     ) -> (usize, &'b ConstantNameAndType) {
-        let method_ref = class_file.cp_info.get_method_ref(index);
-        let class_ref = class_file.cp_info.get_class_ref(method_ref.class_index);
-        let name_and_type = class_file
-            .cp_info
-            .get_name_and_type(method_ref.name_and_type_index);
-        let class_name = class_file.cp_info.get_utf8(class_ref.name_index);
+        // invokespecial and invokestatic also refer interface methods
+        let (class_index, name_and_type_index) = match class_file.cp_info.0.get(index) {
+            Some(ConstPoolItem::ConstantInterfaceMethodref(method_ref)) => {
+                (method_ref.class_index, method_ref.name_and_type_index)
+            }
+            _ => {
+                let method_ref = class_file.cp_info.get_method_ref(index);
+                (method_ref.class_index, method_ref.name_and_type_index)
+            }
+        };
+        let name_and_type = class_file.cp_info.get_name_and_type(name_and_type_index);
+        let class_name = class_file.cp_info.get_class_ref_name(class_index);
         (class_name, name_and_type)
     }
 
-    fn create_new_stack_frame(&mut self, local_variable_length: usize) -> Stackframe {
-        let mut new_stack_frame = Stackframe::new(local_variable_length);
-        let stackframe = self.get_last_stackframe();
+    // the method is looked up from class_name through its super classes
+    fn resolve_method_class(
+        &mut self,
+        string_map: &mut StringPool,
+        class_file: &Custom,
+        class_name: usize,
+        name_and_type: &ConstantNameAndType,
+    ) -> usize {
+        let method_name = class_file.cp_info.get_utf8(name_and_type.name_index);
+        let method_descriptor = class_file.cp_info.get_utf8(name_and_type.descriptor_index);
+        self.find_method_class(string_map, class_name, method_name, method_descriptor)
+            .unwrap_or(class_name)
+    }
 
-        // TBD need to fix this
-        let mut variables: Vec<_> = stackframe
-            .operand_stack
-            .stack
-            .iter()
-            .rev()
-            .map(|operand_item| Item::from(operand_item.clone()))
-            .collect();
-        let mut variables = variables.drain(0..local_variable_length).rev().collect();
+    // the arguments are moved from the caller's operand_stack to the first local variables
+    fn create_new_stack_frame(
+        &mut self,
+        parameter_length: usize,
+        local_variable_length: usize,
+    ) -> Stackframe {
+        let mut new_stack_frame = Stackframe::new(local_variable_length);
+        let stack = &mut self.get_last_stackframe().operand_stack.stack;
+        let mut variables = stack.split_off(stack.len() - parameter_length);
         new_stack_frame.local_variables.append(&mut variables);
-
-        // TBD need to fix this
-        for _ in 0..local_variable_length {
-            let _ = stackframe.operand_stack.stack.pop();
+        if new_stack_frame.local_variables.len() < local_variable_length {
+            new_stack_frame
+                .local_variables
+                .resize(local_variable_length, Item::Null);
         }
 
         new_stack_frame
@@ -1138,11 +2225,16 @@ pub fn set_static_fields(
     class: &Custom,
     static_fields: &mut StaticFields,
 ) {
-    for field in class.fields.iter() {
+    let fields = static_fields.entry(class.this_class_name()).or_default();
+    for field in class.fields.iter().filter(|field| field.is_static()) {
         let field_name = class.cp_info.get_utf8(field.name_index);
-        let value =
-            create_uninitialized_item(&class.get_descriptor(string_map, field.descriptor_index));
-        static_fields.insert((class.this_class_name(), field_name), value);
+        let value = match field.constant_value_index() {
+            Some(constant_value_index) => class.cp_info.get_constant_value(constant_value_index),
+            None => {
+                create_uninitialized_item(&class.get_descriptor(string_map, field.descriptor_index))
+            }
+        };
+        fields.insert(field_name, value);
     }
 }
 
@@ -1158,20 +2250,36 @@ pub fn setup_static_fields(string_map: &mut StringPool, class_map: &ClassMap) ->
     let field_name_id = string_map.insert(String::from("out"));
     let class_ref_id = string_map.insert(String::from("java/io/PrintStream"));
 
-    static_fields.insert(
-        (class_name_id, field_name_id),
-        (Item::Classref(class_ref_id), Item::Null),
-    );
+    static_fields
+        .entry(class_name_id)
+        .or_default()
+        .insert(field_name_id, (Item::Classref(class_ref_id), Item::Null));
 
     static_fields
 }
 
+// returns None for primitive components like "I"
+fn component_class_name(component: &str) -> Option<&str> {
+    if component.starts_with('[') {
+        Some(component)
+    } else if component.starts_with('L') && component.ends_with(';') {
+        Some(&component[1..component.len() - 1])
+    } else {
+        None
+    }
+}
+
 // TBD need to create system to express uninitialized value
 pub fn create_uninitialized_item(descriptor: &FieldDescriptor) -> (Item, Item) {
     match descriptor {
-        FieldDescriptor::BaseType(BaseType::I) => (Item::Int(0), Item::Null),
-        FieldDescriptor::BaseType(BaseType::J) => (Item::Long(0), Item::Long(0)),
-        FieldDescriptor::BaseType(BaseType::Z) => (Item::Boolean(true), Item::Null),
-        _ => unimplemented!("should implement"),
+        FieldDescriptor::BaseType(BaseType::B)
+        | FieldDescriptor::BaseType(BaseType::C)
+        | FieldDescriptor::BaseType(BaseType::I)
+        | FieldDescriptor::BaseType(BaseType::S) => (Item::Int(0), Item::Null),
+        FieldDescriptor::BaseType(BaseType::F) => (Item::Float(0.0), Item::Null),
+        FieldDescriptor::BaseType(BaseType::J) => create_long_items(0),
+        FieldDescriptor::BaseType(BaseType::D) => create_double_items(0.0),
+        FieldDescriptor::BaseType(BaseType::Z) => (Item::Boolean(false), Item::Null),
+        FieldDescriptor::ObjectType(_) | FieldDescriptor::ArrayType(_) => (Item::Null, Item::Null),
     }
 }
@@ -1,5 +1,7 @@
 use crate::attribute::defs::Attribute;
-use crate::utils::extract_x_byte_as_usize;
+use crate::constant::ConstantPool;
+use crate::string_pool::StringPool;
+use crate::utils::{extract_x_byte_as_usize, write_x_byte_as_usize};
 use std::fmt;
 
 #[derive(Debug)]
@@ -12,13 +14,25 @@ pub struct Field {
 }
 
 impl Field {
-    pub fn new(inputs: &[u8], index: usize) -> (Field, usize) {
+    pub fn new(
+        string_pool: &mut StringPool,
+        constant_pool: &ConstantPool,
+        inputs: &[u8],
+        index: usize,
+    ) -> (Field, usize) {
         let (access_flags, index) = extract_x_byte_as_usize(inputs, index, 2);
         let access_flags = extract_access_flags(access_flags);
 
         let (name_index, index) = extract_x_byte_as_usize(inputs, index, 2);
         let (descriptor_index, index) = extract_x_byte_as_usize(inputs, index, 2);
-        let (attributes_count, index) = extract_x_byte_as_usize(inputs, index, 2);
+        let (attributes_count, mut index) = extract_x_byte_as_usize(inputs, index, 2);
+        let mut attribute_info = Vec::with_capacity(attributes_count);
+        for _ in 0..attributes_count {
+            let (attribute, updated_index) =
+                Attribute::new(string_pool, constant_pool, inputs, index);
+            index = updated_index;
+            attribute_info.push(attribute);
+        }
 
         (
             Field {
@@ -26,11 +40,39 @@ impl Field {
                 name_index,
                 descriptor_index,
                 attributes_count,
-                attribute_info: vec![],
+                attribute_info,
             },
             index,
         )
     }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_x_byte_as_usize(output, self.access_flags.to_usize(), 2);
+        write_x_byte_as_usize(output, self.name_index, 2);
+        write_x_byte_as_usize(output, self.descriptor_index, 2);
+        write_x_byte_as_usize(output, self.attribute_info.len(), 2);
+        for attribute in self.attribute_info.iter() {
+            attribute.write(output);
+        }
+    }
+}
+
+impl Field {
+    pub fn is_static(&self) -> bool {
+        self.access_flags
+            .0
+            .iter()
+            .any(|flag| matches!(flag, FieldAccessFlag::AccStatic))
+    }
+
+    pub fn constant_value_index(&self) -> Option<usize> {
+        self.attribute_info
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::ConstantValue(item) => Some(item.constantvalue_index as usize),
+                _ => None,
+            })
+    }
 }
 
 impl fmt::Display for Field {
@@ -87,7 +129,7 @@ impl From<&str> for FieldDescriptor {
             "S" => FieldDescriptor::BaseType(BaseType::S),
             "Z" => FieldDescriptor::BaseType(BaseType::Z),
             "L" => FieldDescriptor::ObjectType(input[1..].to_string()),
-            "[" => unimplemented!("need to implement for Array"),
+            "[" => FieldDescriptor::ArrayType(Box::new(FieldDescriptor::from(&input[1..]))),
             _ => panic!("failed to convert {} to FieldDescriptor", input),
         }
     }
@@ -105,11 +147,18 @@ fn extract_access_flags(num: usize) -> FieldAccessFlags {
     crate::add_flags!(&mut access_flags, num, FieldAccessFlag::AccSynthetic);
     crate::add_flags!(&mut access_flags, num, FieldAccessFlag::AccEnum);
 
-    FieldAccessFlags(access_flags)
+    FieldAccessFlags(access_flags, num as u16)
 }
 
 #[derive(Debug)]
-pub struct FieldAccessFlags(Vec<FieldAccessFlag>);
+// (flags, raw value). the raw value also keeps the bits which have no FieldAccessFlag
+pub struct FieldAccessFlags(Vec<FieldAccessFlag>, u16);
+impl FieldAccessFlags {
+    pub fn to_usize(&self) -> usize {
+        self.1 as usize
+    }
+}
+
 impl fmt::Display for FieldAccessFlags {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut result = Vec::with_capacity(self.0.len());
@@ -120,7 +169,7 @@ impl fmt::Display for FieldAccessFlags {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum FieldAccessFlag {
     Unknown = 0x0000,
     AccPublic = 0x0001,
@@ -1,14 +1,18 @@
 use std::collections::HashMap;
 
 use crate::constant::ConstantPool;
+use crate::object::ObjectMap;
 use crate::operand::Item;
 use crate::stackframe::Stackframe;
 use crate::string_pool::StringPool;
+use crate::utils::{java_double_to_string, java_float_to_string};
 use crate::wasm::print_log;
 
 #[derive(Debug)]
 pub struct BuiltIn {
     pub class_name: usize,
+    pub super_class_name: Option<usize>,
+    pub interface_names: Vec<usize>,
     pub methods: HashMap<usize, BuiltInMethod>,
 }
 
@@ -16,6 +20,8 @@ impl BuiltIn {
     pub fn new(class_name: usize) -> BuiltIn {
         BuiltIn {
             class_name,
+            super_class_name: None,
+            interface_names: vec![],
             methods: HashMap::new(),
         }
     }
@@ -44,9 +50,17 @@ impl BuiltInMethod {
                 "(J)V" | "(D)V" => 2,
                 _ => 1,
             },
+            BuitlInCodeType::JavaLangThrowableInit => match descriptor.as_ref() {
+                "()V" => 1,
+                "(Ljava/lang/String;)V" => 2,
+                _ => unimplemented!("Throwable.<init>{} is not supported", descriptor),
+            },
+            BuitlInCodeType::JavaLangStringEquals => 2,
             BuitlInCodeType::JavaLangSystemInit
             | BuitlInCodeType::JavaLangObjectInit
-            | BuitlInCodeType::JavaLangObjectToString => 1,
+            | BuitlInCodeType::JavaLangObjectToString
+            | BuitlInCodeType::JavaLangStringHashCode
+            | BuitlInCodeType::JavaLangThrowableGetMessage => 1,
         }
     }
 
@@ -55,6 +69,7 @@ impl BuiltInMethod {
         string_map: &mut StringPool,
         constant_pool: &ConstantPool,
         stackframes: &mut Vec<Stackframe>,
+        object_map: &mut ObjectMap,
     ) {
         let mut stackframe = stackframes.pop().expect("should has stack_frame");
         match self.code_type {
@@ -73,18 +88,8 @@ impl BuiltInMethod {
                         Item::Int(value) => {
                             print_log(&format!("{}", value));
                         }
-                        Item::Long(second) => {
-                            if let Some(Item::Long(first)) = stackframe.local_variables.get(1) {
-                                let value: u64 = (((*first as u64) << 32) as u64) | *second as u64;
-                                let value = if value > 0x7fffffffffffffff {
-                                    -1 * ((value ^ 0xffffffffffffffff) + 1) as i64
-                                } else {
-                                    value as i64
-                                };
-                                print_log(&format!("{}", value));
-                            } else {
-                                unreachable!("should exist long second item")
-                            }
+                        Item::Long(value) => {
+                            print_log(&format!("{}", value));
                             let _ = stackframe.operand_stack.stack.pop();
                         }
                         // TBD should fix to output value correctly
@@ -92,25 +97,10 @@ impl BuiltInMethod {
                             print_log(&format!("objectref: {}", object_ref));
                         }
                         Item::Float(value) => {
-                            print_log(&format!("{}", value));
+                            print_log(&java_float_to_string(*value));
                         }
-                        Item::Double(second) => {
-                            if let Some(Item::Double(first)) = stackframe.local_variables.get(1) {
-                                let value: u64 = (((*first as u64) << 32) as u64) | *second as u64;
-                                let s: i64 = if value >> 63 == 0 as u64 { 1 } else { -1 };
-                                let e = (value >> 52 as i32) & 0x7ff;
-                                let m = if e == 0 {
-                                    ((value & 0xfffffffffffff) << 1) as i64
-                                } else {
-                                    ((value & 0xfffffffffffff) | 0x10000000000000) as i64
-                                };
-                                print_log(&format!(
-                                    "{}",
-                                    (s * m) as f64 * f64::powf(2.0f64, e as f64 - 1075 as f64)
-                                ));
-                            } else {
-                                unreachable!("should exist long second item")
-                            }
+                        Item::Double(value) => {
+                            print_log(&java_double_to_string(*value));
                             let _ = stackframe.operand_stack.stack.pop();
                         }
                         _ => unimplemented!(),
@@ -131,14 +121,80 @@ impl BuiltInMethod {
                 let string_id = string_map.insert(val.to_string());
                 stackframe.operand_stack.stack.push(Item::String(string_id));
             }
+            // s[0]*31^(n-1) + s[1]*31^(n-2) + ... + s[n-1] over the UTF-16 code units
+            BuitlInCodeType::JavaLangStringHashCode => {
+                let value = extract_string(string_map, &stackframe);
+                let hash_code = value
+                    .encode_utf16()
+                    .fold(0i32, |hash, c| hash.wrapping_mul(31).wrapping_add(c as i32));
+                let stackframe = stackframes.last_mut().expect("should exist stackframe");
+                stackframe.operand_stack.stack.push(Item::Int(hash_code));
+            }
+            BuitlInCodeType::JavaLangStringEquals => {
+                let value = extract_string(string_map, &stackframe);
+                let is_equal = match stackframe.local_variables.get(1) {
+                    Some(Item::String(id)) => string_map.get_value(id) == value,
+                    _ => false,
+                };
+                let stackframe = stackframes.last_mut().expect("should exist stackframe");
+                stackframe
+                    .operand_stack
+                    .stack
+                    .push(Item::Int(if is_equal { 1 } else { 0 }));
+            }
+            BuitlInCodeType::JavaLangThrowableInit => {
+                let object_id = extract_objectref_id(&stackframe);
+                if let Some(message) = stackframe.local_variables.get(1) {
+                    let class_name = string_map.insert(String::from("java/lang/Throwable"));
+                    let field_name = string_map.insert(String::from("detailMessage"));
+                    object_map
+                        .get(&object_id)
+                        .expect("should exist object_ref in object_map")
+                        .field_map
+                        .borrow_mut()
+                        .insert((class_name, field_name), (message.clone(), Item::Null));
+                }
+            }
+            BuitlInCodeType::JavaLangThrowableGetMessage => {
+                let object_id = extract_objectref_id(&stackframe);
+                let class_name = string_map.insert(String::from("java/lang/Throwable"));
+                let field_name = string_map.insert(String::from("detailMessage"));
+                let message = object_map
+                    .get(&object_id)
+                    .expect("should exist object_ref in object_map")
+                    .field_map
+                    .borrow()
+                    .get(&(class_name, field_name))
+                    .map_or(Item::Null, |(message, _)| message.clone());
+                let stackframe = stackframes.last_mut().expect("should exist stackframe");
+                stackframe.operand_stack.stack.push(message);
+            }
         }
     }
 }
 
+fn extract_objectref_id(stackframe: &Stackframe) -> usize {
+    match stackframe.local_variables.first() {
+        Some(Item::Objectref(object_id)) => *object_id,
+        item => unreachable!("should be Objectref. actual: {:?}", item),
+    }
+}
+
+fn extract_string(string_map: &mut StringPool, stackframe: &Stackframe) -> String {
+    match stackframe.local_variables.first() {
+        Some(Item::String(id)) => string_map.get_value(id),
+        item => unreachable!("should be String. actual: {:?}", item),
+    }
+}
+
 #[derive(Debug)]
 pub enum BuitlInCodeType {
     Println,
     JavaLangObjectInit,
     JavaLangSystemInit,
     JavaLangObjectToString,
+    JavaLangStringHashCode,
+    JavaLangStringEquals,
+    JavaLangThrowableInit,
+    JavaLangThrowableGetMessage,
 }
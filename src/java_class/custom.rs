@@ -60,7 +60,7 @@ impl Custom {
         let (fields_count, mut index) = extract_x_byte_as_usize(input, index, 2);
         let mut fields = Vec::with_capacity(fields_count);
         for _ in 0..fields_count {
-            let (field, updated_index) = Field::new(input, index);
+            let (field, updated_index) = Field::new(string_pool, &cp_info, input, index);
             index = updated_index;
             fields.push(field);
         }
@@ -104,6 +104,36 @@ impl Custom {
         )
     }
 
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_x_byte_as_usize(output, self.magic as usize, 4);
+        write_x_byte_as_usize(output, self.minor_version as usize, 2);
+        write_x_byte_as_usize(output, self.major_version as usize, 2);
+        self.cp_info.write(output);
+        write_x_byte_as_usize(output, self.access_flags.to_usize(), 2);
+        write_x_byte_as_usize(output, self.this_class, 2);
+        write_x_byte_as_usize(output, self.super_class, 2);
+
+        write_x_byte_as_usize(output, self.interfaces.len(), 2);
+        for Interface(interface_index) in self.interfaces.iter() {
+            write_x_byte_as_usize(output, *interface_index, 2);
+        }
+
+        write_x_byte_as_usize(output, self.fields.len(), 2);
+        for field in self.fields.iter() {
+            field.write(output);
+        }
+
+        write_x_byte_as_usize(output, self.methods.len(), 2);
+        for method in self.methods.iter() {
+            method.write(output);
+        }
+
+        write_x_byte_as_usize(output, self.attributes.len(), 2);
+        for attribute in self.attributes.iter() {
+            attribute.write(output);
+        }
+    }
+
     pub fn get_entry_method(&self) -> Option<&Method> {
         if let Some(main_index) = self.cp_info.get_main_index() {
             return self.methods.iter().find(|method| {
@@ -159,6 +189,21 @@ impl Custom {
         self.cp_info.get_utf8(class_ref.name_index)
     }
 
+    // java/lang/Object doesn't have the super class
+    pub fn super_class_name(&self) -> Option<usize> {
+        if self.super_class == 0 {
+            return None;
+        }
+        Some(self.cp_info.get_class_ref_name(self.super_class))
+    }
+
+    pub fn interface_names(&self) -> Vec<usize> {
+        self.interfaces
+            .iter()
+            .map(|Interface(index)| self.cp_info.get_class_ref_name(*index))
+            .collect()
+    }
+
     pub fn get_method(&self, name_index: usize, descriptor_index: usize) -> Option<&Method> {
         self.methods
             .iter()
@@ -276,11 +321,12 @@ fn extract_access_flags(num: usize) -> AccessFlags {
     crate::add_flags!(&mut access_flags, num, AccessFlag::AccSynthetic);
     crate::add_flags!(&mut access_flags, num, AccessFlag::AccAnnotation);
     crate::add_flags!(&mut access_flags, num, AccessFlag::AccEnum);
+    crate::add_flags!(&mut access_flags, num, AccessFlag::AccModule);
 
-    AccessFlags(access_flags)
+    AccessFlags(access_flags, num as u16)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum AccessFlag {
     AccPublic = 0x0001,
     AccFinal = 0x0010,
@@ -290,6 +336,7 @@ pub enum AccessFlag {
     AccSynthetic = 0x1000,
     AccAnnotation = 0x2000,
     AccEnum = 0x4000,
+    AccModule = 0x8000,
 }
 
 impl fmt::Display for AccessFlag {
@@ -303,6 +350,7 @@ impl fmt::Display for AccessFlag {
             AccessFlag::AccSynthetic => write!(f, "ACC_SYNTHETIC"),
             AccessFlag::AccAnnotation => write!(f, "ACC_ANNOTATION"),
             AccessFlag::AccEnum => write!(f, "ACC_ENUM"),
+            AccessFlag::AccModule => write!(f, "ACC_MODULE"),
         }
     }
 }
@@ -318,13 +366,21 @@ impl From<usize> for AccessFlag {
             0x1000 => AccessFlag::AccSynthetic,
             0x2000 => AccessFlag::AccAnnotation,
             0x4000 => AccessFlag::AccEnum,
+            0x8000 => AccessFlag::AccModule,
             _ => panic!("failed to convert {} to AccessFlag", num),
         }
     }
 }
 
 #[derive(Debug)]
-pub struct AccessFlags(Vec<AccessFlag>);
+// (flags, raw value). the raw value also keeps the bits which have no AccessFlag
+pub struct AccessFlags(Vec<AccessFlag>, u16);
+impl AccessFlags {
+    pub fn to_usize(&self) -> usize {
+        self.1 as usize
+    }
+}
+
 impl fmt::Display for AccessFlags {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut result = Vec::with_capacity(self.0.len());
@@ -334,3 +390,95 @@ impl fmt::Display for AccessFlags {
         write!(f, "flags: {}", result.join(", "))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constant::ConstPoolItem;
+    use std::fs;
+
+    #[test]
+    fn custom_write_round_trip() {
+        let mut paths: Vec<_> = fs::read_dir("tests/class")
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().map_or(false, |ext| ext == "class"))
+            .collect();
+        paths.sort();
+        assert!(!paths.is_empty());
+
+        for path in paths.iter() {
+            let inputs = read_file(path).unwrap();
+            let mut string_pool = StringPool::new();
+            let (class_file, index) = Custom::new(&mut string_pool, &inputs, 0);
+            assert_eq!(index, inputs.len(), "{:?}", path);
+
+            let mut output = vec![];
+            class_file.write(&mut output);
+            assert!(output == inputs, "{:?} is not written back as is", path);
+        }
+    }
+
+    #[test]
+    fn custom_write_patched_utf8() {
+        let inputs = read_file(&"tests/class/HelloWorld.class").unwrap();
+        let mut string_pool = StringPool::new();
+        let (mut class_file, _) = Custom::new(&mut string_pool, &inputs, 0);
+        let sourcefile_index = class_file
+            .attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::SourceFile(item) => Some(item.sourcefile_index as usize),
+                _ => None,
+            })
+            .unwrap();
+        if let ConstPoolItem::ConstantUtf8(item) = &mut class_file.cp_info.0[sourcefile_index] {
+            item.bytes = encode_modified_utf8("Patched\u{0}.java");
+        }
+
+        // the length of ConstantUtf8 is taken from the new bytes
+        let mut output = vec![];
+        class_file.write(&mut output);
+        let (patched, index) = Custom::new(&mut string_pool, &output, 0);
+        assert_eq!(index, output.len());
+        assert_eq!(
+            string_pool.get_value(&patched.cp_info.get_utf8(sourcefile_index)),
+            "Patched\u{0}.java"
+        );
+    }
+
+    #[test]
+    fn custom_write_unknown_access_flags() {
+        let mut inputs = read_file(&"tests/class/NullArrayStore.class").unwrap();
+        let (class_file, _) = Custom::new(&mut StringPool::new(), &inputs, 0);
+        let mut cp_info = vec![];
+        class_file.cp_info.write(&mut cp_info);
+        let class_flags_index = 8 + cp_info.len();
+        // magic, versions, access_flags, this_class, super_class, interfaces and fields_count
+        let field_flags_index = class_flags_index + 6 + 2 + class_file.interfaces.len() * 2 + 2;
+        let method_flags_index = field_flags_index
+            + class_file.fields.iter().fold(0, |length, field| {
+                let mut output = vec![];
+                field.write(&mut output);
+                length + output.len()
+            })
+            + 2;
+
+        // 0x0002 of a class, 0x0020 of a field and 0x4000 of a method are not defined
+        inputs[class_flags_index + 1] |= 0x02;
+        inputs[field_flags_index + 1] |= 0x20;
+        inputs[method_flags_index] |= 0x40;
+
+        let (class_file, index) = Custom::new(&mut StringPool::new(), &inputs, 0);
+        assert_eq!(index, inputs.len());
+        assert_eq!(class_file.access_flags.to_usize(), 0x0023);
+        assert_eq!(class_file.fields[0].access_flags.to_usize(), 0x0020);
+        assert_eq!(class_file.methods[0].access_flags.to_usize(), 0x4001);
+        let mut output = vec![];
+        class_file.write(&mut output);
+        assert!(
+            output == inputs,
+            "unknown access flags are not written back"
+        );
+    }
+}
@@ -11,14 +11,84 @@ pub fn setup_class_map(string_pool: &mut StringPool) -> HashMap<usize, JavaClass
     let (java_lang_object_name, java_lang_object) = create_java_lang_object(string_pool);
     let (java_lang_integer_name, java_lang_integer) = create_java_lang_integer(string_pool);
     let (java_lang_system_name, java_lang_system) = create_java_lang_system(string_pool);
+    let (java_lang_string_name, java_lang_string) = create_java_lang_string(string_pool);
 
     class_map.insert(print_stream_name, print_stream);
     class_map.insert(java_lang_object_name, java_lang_object);
     class_map.insert(java_lang_integer_name, java_lang_integer);
     class_map.insert(java_lang_system_name, java_lang_system);
+    class_map.insert(java_lang_string_name, java_lang_string);
+    for (class_name, super_class_name) in THROWABLE_CLASSES.iter() {
+        let (throwable_name, throwable) =
+            create_throwable_class(string_pool, class_name, super_class_name);
+        class_map.insert(throwable_name, throwable);
+    }
     class_map
 }
 
+// (class_name, super_class_name)
+const THROWABLE_CLASSES: [(&str, &str); 15] = [
+    ("java/lang/Throwable", "java/lang/Object"),
+    ("java/lang/Exception", "java/lang/Throwable"),
+    ("java/lang/Error", "java/lang/Throwable"),
+    ("java/lang/RuntimeException", "java/lang/Exception"),
+    (
+        "java/lang/IllegalArgumentException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/IllegalStateException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/ArithmeticException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/NullPointerException",
+        "java/lang/RuntimeException",
+    ),
+    ("java/lang/ClassCastException", "java/lang/RuntimeException"),
+    (
+        "java/lang/NegativeArraySizeException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/IndexOutOfBoundsException",
+        "java/lang/RuntimeException",
+    ),
+    (
+        "java/lang/ArrayIndexOutOfBoundsException",
+        "java/lang/IndexOutOfBoundsException",
+    ),
+    ("java/lang/LinkageError", "java/lang/Error"),
+    (
+        "java/lang/ExceptionInInitializerError",
+        "java/lang/LinkageError",
+    ),
+    ("java/lang/NoClassDefFoundError", "java/lang/LinkageError"),
+];
+
+fn create_throwable_class(
+    string_pool: &mut StringPool,
+    class_name: &str,
+    super_class_name: &str,
+) -> (usize, JavaClass) {
+    let class_name_id = string_pool.insert(class_name.to_string());
+    let mut throwable = BuiltIn::new(class_name_id);
+    throwable.super_class_name = Some(string_pool.insert(super_class_name.to_string()));
+    let init_name_id = string_pool.insert(String::from("<init>"));
+    let init = BuiltInMethod::new(init_name_id, BuitlInCodeType::JavaLangThrowableInit);
+    throwable.methods.insert(init_name_id, init);
+    let get_message_name_id = string_pool.insert(String::from("getMessage"));
+    let get_message = BuiltInMethod::new(
+        get_message_name_id,
+        BuitlInCodeType::JavaLangThrowableGetMessage,
+    );
+    throwable.methods.insert(get_message_name_id, get_message);
+    (class_name_id, JavaClass::BuiltIn(throwable))
+}
+
 fn create_print_stream(string_pool: &mut StringPool) -> (usize, JavaClass) {
     let class_name_id = string_pool.insert(String::from("java/io/PrintStream"));
     let mut print_stream = BuiltIn::new(class_name_id);
@@ -52,6 +122,30 @@ fn create_java_lang_system(string_pool: &mut StringPool) -> (usize, JavaClass) {
     )
 }
 
+// String instances are Item::String. hashCode and equals are used by switch on String
+fn create_java_lang_string(string_pool: &mut StringPool) -> (usize, JavaClass) {
+    let java_lang_string_name_id = string_pool.insert(String::from("java/lang/String"));
+    let mut java_lang_string = BuiltIn::new(java_lang_string_name_id);
+    java_lang_string.super_class_name = Some(string_pool.insert(String::from("java/lang/Object")));
+    java_lang_string.interface_names = vec![
+        string_pool.insert(String::from("java/io/Serializable")),
+        string_pool.insert(String::from("java/lang/Comparable")),
+        string_pool.insert(String::from("java/lang/CharSequence")),
+    ];
+    let hash_code_name_id = string_pool.insert(String::from("hashCode"));
+    let hash_code = BuiltInMethod::new(hash_code_name_id, BuitlInCodeType::JavaLangStringHashCode);
+    java_lang_string
+        .methods
+        .insert(hash_code_name_id, hash_code);
+    let equals_name_id = string_pool.insert(String::from("equals"));
+    let equals = BuiltInMethod::new(equals_name_id, BuitlInCodeType::JavaLangStringEquals);
+    java_lang_string.methods.insert(equals_name_id, equals);
+    (
+        java_lang_string_name_id,
+        JavaClass::BuiltIn(java_lang_string),
+    )
+}
+
 fn create_java_lang_integer(string_pool: &mut StringPool) -> (usize, JavaClass) {
     let java_lang_integer_name_id = string_pool.insert(String::from("java/lang/Integer"));
     let mut java_lang_integer = BuiltIn::new(java_lang_integer_name_id);
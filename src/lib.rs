@@ -32,7 +32,8 @@ use std::path::Path;
 #[macro_use]
 extern crate lazy_static;
 
-pub fn execute(file_name: String, debug_mode: usize) {
+// returns the exit status
+pub fn execute(file_name: String, debug_mode: usize) -> i32 {
     RJ_OPTION.lock().unwrap().debug_mode = debug_mode;
     let class_name = file_name + ".class";
     let buffer = get_file_content(&class_name);
@@ -46,7 +47,7 @@ pub fn execute(file_name: String, debug_mode: usize) {
     };
 
     let mut context = Context::new(&mut string_pool, class_map, &class_file, parent_path);
-    context.run_entry_file(&mut string_pool, class_file);
+    context.run_entry_file(&mut string_pool, class_file)
 }
 
 #[cfg(target_arch = "wasm32")]
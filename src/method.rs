@@ -47,6 +47,13 @@ impl Method {
         )
     }
 
+    pub fn is_static(&self) -> bool {
+        self.access_flags
+            .0
+            .iter()
+            .any(|flag| matches!(flag, MethodAccessFlag::AccStatic))
+    }
+
     pub fn extract_code<'a>(&self) -> Option<&Code> {
         if let Some(attribute) = self.attribute_info.iter().find(|attribute| {
             if let Attribute::Code(_) = attribute {
@@ -73,6 +80,16 @@ impl Method {
         }
         Ok(())
     }
+
+    pub fn write(&self, output: &mut Vec<u8>) {
+        write_x_byte_as_usize(output, self.access_flags.to_usize(), 2);
+        write_x_byte_as_usize(output, self.name_index, 2);
+        write_x_byte_as_usize(output, self.descriptor_index, 2);
+        write_x_byte_as_usize(output, self.attribute_info.len(), 2);
+        for attribute in self.attribute_info.iter() {
+            attribute.write(output);
+        }
+    }
 }
 
 impl fmt::Display for Method {
@@ -96,6 +113,34 @@ impl fmt::Display for Method {
     }
 }
 
+// the number of local variable slots which the parameters use. long and double use two slots
+pub fn parameter_slot_length(descriptor: &str) -> usize {
+    let parameters = &descriptor[1..descriptor.find(')').expect("should exist ')'")];
+    let mut chars = parameters.chars();
+    let mut length = 0;
+    while let Some(c) = chars.next() {
+        match c {
+            'J' | 'D' => length += 2,
+            'L' => {
+                chars.by_ref().find(|c| *c == ';');
+                length += 1;
+            }
+            '[' => {
+                let mut component = chars.next();
+                while component == Some('[') {
+                    component = chars.next();
+                }
+                if component == Some('L') {
+                    chars.by_ref().find(|c| *c == ';');
+                }
+                length += 1;
+            }
+            _ => length += 1,
+        }
+    }
+    length
+}
+
 fn extract_access_flags(num: usize) -> MethodAccessFlags {
     let mut access_flags = vec![];
     crate::add_flags!(&mut access_flags, num, MethodAccessFlag::AccPublic);
@@ -112,10 +157,10 @@ fn extract_access_flags(num: usize) -> MethodAccessFlags {
     crate::add_flags!(&mut access_flags, num, MethodAccessFlag::AccStrict);
     crate::add_flags!(&mut access_flags, num, MethodAccessFlag::AccSynthetic);
 
-    MethodAccessFlags(access_flags)
+    MethodAccessFlags(access_flags, num as u16)
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum MethodAccessFlag {
     AccPublic = 0x0001,
     AccPrivate = 0x0002,
@@ -171,7 +216,14 @@ impl From<usize> for MethodAccessFlag {
 }
 
 #[derive(Debug)]
-pub struct MethodAccessFlags(pub Vec<MethodAccessFlag>);
+// (flags, raw value). the raw value also keeps the bits which have no MethodAccessFlag
+pub struct MethodAccessFlags(pub Vec<MethodAccessFlag>, u16);
+impl MethodAccessFlags {
+    pub fn to_usize(&self) -> usize {
+        self.1 as usize
+    }
+}
+
 impl fmt::Display for MethodAccessFlags {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut result = Vec::with_capacity(self.0.len());
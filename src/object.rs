@@ -33,13 +33,14 @@ impl ObjectMap {
     }
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct Objectref {
     pub class_name_id: usize,
-    pub field_map: RefCell<HashMap<(usize, usize), (Item, Item)>>,
+    pub field_map: FieldMap,
     pub is_initialized: bool,
 }
 
+// (class_name, field_name). class_name is the class which declares the field
 pub type FieldMap = RefCell<HashMap<(usize, usize), (Item, Item)>>;
 
 impl Objectref {
@@ -1,14 +1,31 @@
-// use crate::utils::devide_u64_to_two_u32;
 use std::cmp::{Ordering, PartialOrd};
 use std::fmt;
 
-pub fn devide_i64_two_usize(input: i64) -> (usize, usize) {
-    let high_value = ((input >> 32) << 32) as usize;
-    let low_value = (input & 0xFFFFFFFF) as usize;
-    if input > 0 {
-        (low_value, high_value)
-    } else {
-        (0xFFFFFFFF - low_value, 0xFFFFFFFF - high_value)
+// long and double use two slots in operand_stack, local variables, fields and arrays.
+// both slots have the same value, so either of them can be read
+pub fn create_long_items(input: i64) -> (Item, Item) {
+    (Item::Long(input), Item::Long(input))
+}
+
+pub fn create_double_items(input: f64) -> (Item, Item) {
+    (Item::Double(input), Item::Double(input))
+}
+
+// returns None if the items are not long
+pub fn join_long_items(first: &Item, second: &Item) -> Option<i64> {
+    match (first, second) {
+        (Item::Long(first), Item::Long(second)) if first == second => Some(*first),
+        _ => None,
+    }
+}
+
+// returns None if the items are not double
+pub fn join_double_items(first: &Item, second: &Item) -> Option<f64> {
+    match (first, second) {
+        (Item::Double(first), Item::Double(second)) if first.to_bits() == second.to_bits() => {
+            Some(*first)
+        }
+        _ => None,
     }
 }
 
@@ -16,15 +33,16 @@ pub fn devide_i64_two_usize(input: i64) -> (usize, usize) {
 pub enum Item {
     Null,
     Int(i32),
-    Long(usize),
+    Long(i64),
     Float(f32),
-    Double(usize),
+    Double(f64),
     String(usize),
     Boolean(bool),
     Classref(usize),
     Fieldref(usize),
     Objectref(usize),
     Arrayref(usize),
+    ReturnAddress(usize),
 }
 
 impl fmt::Display for Item {
@@ -41,6 +59,20 @@ impl fmt::Display for Item {
             Item::Fieldref(val) => write!(f, "field_ref: {}", val),
             Item::Objectref(val) => write!(f, "object_ref: {}", val),
             Item::Arrayref(val) => write!(f, "array_ref {}", val),
+            Item::ReturnAddress(val) => write!(f, "return_address: {}", val),
+        }
+    }
+}
+
+impl Item {
+    // references are compared by their ids, not by their values
+    pub fn is_same_reference(&self, other: &Item) -> bool {
+        match (self, other) {
+            (Item::Null, Item::Null) => true,
+            (Item::Objectref(left), Item::Objectref(right))
+            | (Item::Arrayref(left), Item::Arrayref(right))
+            | (Item::String(left), Item::String(right)) => left == right,
+            _ => false,
         }
     }
 }
@@ -59,7 +91,7 @@ impl PartialOrd for Item {
                     Ordering::Less
                 }
             }),
-            (Item::Double(left), Item::Double(right)) => Some(left.cmp(right)),
+            (Item::Double(left), Item::Double(right)) => left.partial_cmp(right),
             (Item::Boolean(left), Item::Boolean(right)) => Some(left.cmp(right)),
             (Item::Long(left), Item::Long(right)) => Some(left.cmp(right)),
             (Item::Classref(left), Item::Classref(right)) => Some(left.cmp(right)),
@@ -84,47 +116,110 @@ macro_rules! culculate {
     }
 }
 
+// int and long arithmetic wraps around on overflow like Java
+macro_rules! wrapping_culculate {
+    ($name:ident, $extract_method:ident, $type:ident, $method:ident) => {
+        pub fn $name(&mut self) -> Item {
+            let (first, second) = self.$extract_method();
+            Item::$type(first.$method(second))
+        }
+    };
+}
+
+macro_rules! wrapping_culculate_long {
+    ($name:ident, $method:ident) => {
+        pub fn $name(&mut self) -> (Item, Item) {
+            let (first, second) = self.extract_long_values_as_i64();
+            create_long_items(first.$method(second))
+        }
+    };
+}
+
+macro_rules! culculate_double {
+    ($name:ident, $op:tt) => {
+      pub fn $name(&mut self) -> (Item, Item) {
+          let (first, second) = self.extract_double_values_as_f64();
+          create_double_items(first $op second)
+      }
+    }
+}
+
+macro_rules! culculate_long {
+    ($name:ident, $op:tt) => {
+      pub fn $name(&mut self) -> (Item, Item) {
+          let (first, second) = self.extract_long_values_as_i64();
+          create_long_items(first $op second)
+      }
+    }
+}
+
 impl OperandStack {
     pub fn new() -> Self {
         OperandStack { stack: vec![] }
     }
 
     fn extract_long_values_as_i64(&mut self) -> (i64, i64) {
-        match (
-            self.stack.pop(),
-            self.stack.pop(),
-            self.stack.pop(),
-            self.stack.pop(),
-        ) {
-            (
-                Some(Item::Long(second_2)),
-                Some(Item::Long(second_1)),
-                Some(Item::Long(first_2)),
-                Some(Item::Long(first_1)),
-            ) => {
-                let second: u64 = (((second_1 as u64) << 32) as u64) | second_2 as u64;
-                let second = if second > 0x7fffffffffffffff {
-                    -1 * ((second ^ 0xffffffffffffffff) + 1) as i64
-                } else {
-                    second as i64
-                };
-                let first: u64 = (((first_1 as u64) << 32) as u64) | first_2 as u64;
-                let first = if first > 0x7fffffffffffffff {
-                    -1 * ((first ^ 0xffffffffffffffff) + 1) as i64
-                } else {
-                    first as i64
-                };
-                (first, second)
+        let second = self.extract_long_value_as_i64();
+        let first = self.extract_long_value_as_i64();
+        (first, second)
+    }
+
+    fn extract_double_values_as_f64(&mut self) -> (f64, f64) {
+        let second = self.extract_double_value_as_f64();
+        let first = self.extract_double_value_as_f64();
+        (first, second)
+    }
+
+    pub fn extract_long_value_as_i64(&mut self) -> i64 {
+        match (self.stack.pop(), self.stack.pop()) {
+            (Some(second), Some(first)) => join_long_items(&first, &second).unwrap_or_else(|| {
+                panic!(
+                    "failed to extract long value
+value: {:?}, {:?}",
+                    first, second
+                )
+            }),
+            (second, first) => panic!(
+                "failed to extract long value
+value: {:?}, {:?}",
+                first, second
+            ),
+        }
+    }
+
+    pub fn extract_double_value_as_f64(&mut self) -> f64 {
+        match (self.stack.pop(), self.stack.pop()) {
+            (Some(second), Some(first)) => {
+                join_double_items(&first, &second).unwrap_or_else(|| {
+                    panic!(
+                        "failed to extract double value
+value: {:?}, {:?}",
+                        first, second
+                    )
+                })
             }
-            (second_2, second_1, first_2, first_1) => panic!(
-                "failed to extract long values
-first: {:?}, {:?}
-second: {:?}, {:?}",
-                first_1, first_2, second_1, second_2
+            (second, first) => panic!(
+                "failed to extract double value
+value: {:?}, {:?}",
+                first, second
             ),
         }
     }
 
+    fn extract_int_value(&mut self) -> i32 {
+        match self.stack.pop() {
+            Some(Item::Int(value)) => value,
+            value => panic!("failed to extract int value. actual: {:?}", value),
+        }
+    }
+
+    fn extract_float_value(&mut self) -> f32 {
+        match self.stack.pop() {
+            Some(Item::Float(value)) => value,
+            value => panic!("failed to extract float value. actual: {:?}", value),
+        }
+    }
+
     fn extract_int_values(&mut self) -> (i32, i32) {
         match (self.stack.pop(), self.stack.pop()) {
             (Some(Item::Int(second)), Some(Item::Int(first))) => (first, second),
@@ -149,11 +244,38 @@ second: {:?}",
         }
     }
 
-    culculate!(iadd, extract_int_values, Int, +);
-    culculate!(isub, extract_int_values, Int, -);
-    culculate!(imul, extract_int_values, Int, *);
-    culculate!(idiv, extract_int_values, Int, /);
-    culculate!(irem, extract_int_values, Int, %);
+    // the remainder has the same sign as the dividend.
+    // MIN / -1 is MIN and MIN % -1 is 0
+    wrapping_culculate!(iadd, extract_int_values, Int, wrapping_add);
+    wrapping_culculate!(isub, extract_int_values, Int, wrapping_sub);
+    wrapping_culculate!(imul, extract_int_values, Int, wrapping_mul);
+    wrapping_culculate!(idiv, extract_int_values, Int, wrapping_div);
+    wrapping_culculate!(irem, extract_int_values, Int, wrapping_rem);
+
+    pub fn ineg(&mut self) -> Item {
+        let value = self.extract_int_value();
+        Item::Int(value.wrapping_neg())
+    }
+
+    culculate!(iand, extract_int_values, Int, &);
+    culculate!(ior, extract_int_values, Int, |);
+    culculate!(ixor, extract_int_values, Int, ^);
+
+    // the shift distance is masked with 0x1f for int
+    pub fn ishl(&mut self) -> Item {
+        let (value, distance) = self.extract_int_values();
+        Item::Int(value << (distance & 0x1f))
+    }
+
+    pub fn ishr(&mut self) -> Item {
+        let (value, distance) = self.extract_int_values();
+        Item::Int(value >> (distance & 0x1f))
+    }
+
+    pub fn iushr(&mut self) -> Item {
+        let (value, distance) = self.extract_int_values();
+        Item::Int(((value as u32) >> (distance & 0x1f)) as i32)
+    }
 
     culculate!(fadd, extract_float_values, Float, +);
     culculate!(fsub, extract_float_values, Float, -);
@@ -161,34 +283,131 @@ second: {:?}",
     culculate!(fdiv, extract_float_values, Float, /);
     culculate!(frem, extract_float_values, Float, %);
 
-    pub fn ladd(&mut self) -> (Item, Item) {
-        let (first, second) = self.extract_long_values_as_i64();
-        let (first, second) = devide_i64_two_usize(first + second);
-        (Item::Long(first), Item::Long(second))
+    pub fn fneg(&mut self) -> Item {
+        let value = self.extract_float_value();
+        Item::Float(-value)
     }
 
-    pub fn lsub(&mut self) -> (Item, Item) {
-        let (first, second) = self.extract_long_values_as_i64();
-        let (first, second) = devide_i64_two_usize(first + second);
-        (Item::Long(first), Item::Long(second))
+    wrapping_culculate_long!(ladd, wrapping_add);
+    wrapping_culculate_long!(lsub, wrapping_sub);
+    wrapping_culculate_long!(lmul, wrapping_mul);
+    wrapping_culculate_long!(ldiv, wrapping_div);
+    wrapping_culculate_long!(lrem, wrapping_rem);
+
+    pub fn lneg(&mut self) -> (Item, Item) {
+        let value = self.extract_long_value_as_i64();
+        create_long_items(value.wrapping_neg())
     }
 
-    pub fn lmul(&mut self) -> (Item, Item) {
-        let (first, second) = self.extract_long_values_as_i64();
-        let (first, second) = devide_i64_two_usize(first * second);
-        (Item::Long(first), Item::Long(second))
+    culculate_double!(dadd, +);
+    culculate_double!(dsub, -);
+    culculate_double!(dmul, *);
+    culculate_double!(ddiv, /);
+    culculate_double!(drem, %);
+
+    pub fn dneg(&mut self) -> (Item, Item) {
+        let value = self.extract_double_value_as_f64();
+        create_double_items(-value)
     }
 
-    pub fn ldiv(&mut self) -> (Item, Item) {
-        let (first, second) = self.extract_long_values_as_i64();
-        let (first, second) = devide_i64_two_usize(first / second);
-        (Item::Long(first), Item::Long(second))
+    // `as` follows the JVMS conversion rules:
+    // NaN becomes 0 and out of range values are saturated.
+    pub fn i2l(&mut self) -> (Item, Item) {
+        let value = self.extract_int_value();
+        create_long_items(value as i64)
     }
 
-    pub fn lrem(&mut self) -> (Item, Item) {
-        let (first, second) = self.extract_long_values_as_i64();
-        let (first, second) = devide_i64_two_usize(first % second);
-        (Item::Long(first), Item::Long(second))
+    pub fn i2f(&mut self) -> Item {
+        let value = self.extract_int_value();
+        Item::Float(value as f32)
+    }
+
+    pub fn i2d(&mut self) -> (Item, Item) {
+        let value = self.extract_int_value();
+        create_double_items(value as f64)
+    }
+
+    pub fn l2i(&mut self) -> Item {
+        let value = self.extract_long_value_as_i64();
+        Item::Int(value as i32)
+    }
+
+    pub fn l2f(&mut self) -> Item {
+        let value = self.extract_long_value_as_i64();
+        Item::Float(value as f32)
+    }
+
+    pub fn l2d(&mut self) -> (Item, Item) {
+        let value = self.extract_long_value_as_i64();
+        create_double_items(value as f64)
+    }
+
+    pub fn f2i(&mut self) -> Item {
+        let value = self.extract_float_value();
+        Item::Int(value as i32)
+    }
+
+    pub fn f2l(&mut self) -> (Item, Item) {
+        let value = self.extract_float_value();
+        create_long_items(value as i64)
+    }
+
+    pub fn f2d(&mut self) -> (Item, Item) {
+        let value = self.extract_float_value();
+        create_double_items(value as f64)
+    }
+
+    pub fn d2i(&mut self) -> Item {
+        let value = self.extract_double_value_as_f64();
+        Item::Int(value as i32)
+    }
+
+    pub fn d2l(&mut self) -> (Item, Item) {
+        let value = self.extract_double_value_as_f64();
+        create_long_items(value as i64)
+    }
+
+    pub fn d2f(&mut self) -> Item {
+        let value = self.extract_double_value_as_f64();
+        Item::Float(value as f32)
+    }
+
+    pub fn i2b(&mut self) -> Item {
+        let value = self.extract_int_value();
+        Item::Int(value as i8 as i32)
+    }
+
+    pub fn i2c(&mut self) -> Item {
+        let value = self.extract_int_value();
+        Item::Int(value as u16 as i32)
+    }
+
+    pub fn i2s(&mut self) -> Item {
+        let value = self.extract_int_value();
+        Item::Int(value as i16 as i32)
+    }
+
+    culculate_long!(land, &);
+    culculate_long!(lor, |);
+    culculate_long!(lxor, ^);
+
+    // the shift distance is int and masked with 0x3f for long
+    pub fn lshl(&mut self) -> (Item, Item) {
+        let distance = self.extract_int_value();
+        let value = self.extract_long_value_as_i64();
+        create_long_items(value << (distance & 0x3f))
+    }
+
+    pub fn lshr(&mut self) -> (Item, Item) {
+        let distance = self.extract_int_value();
+        let value = self.extract_long_value_as_i64();
+        create_long_items(value >> (distance & 0x3f))
+    }
+
+    pub fn lushr(&mut self) -> (Item, Item) {
+        let distance = self.extract_int_value();
+        let value = self.extract_long_value_as_i64();
+        create_long_items(((value as u64) >> (distance & 0x3f)) as i64)
     }
 
     pub fn lcmp(&mut self) -> Item {
@@ -201,6 +420,75 @@ second: {:?}",
         self.compare_value(first, second)
     }
 
+    // NaN makes dcmpl push -1
+    pub fn dcmpl(&mut self) -> Item {
+        let (first, second) = self.extract_double_values_as_f64();
+        if first.is_nan() || second.is_nan() {
+            Item::Int(-1)
+        } else {
+            self.compare_value(first, second)
+        }
+    }
+
+    // NaN makes dcmpg push 1
+    pub fn dcmpg(&mut self) -> Item {
+        let (first, second) = self.extract_double_values_as_f64();
+        if first.is_nan() || second.is_nan() {
+            Item::Int(1)
+        } else {
+            self.compare_value(first, second)
+        }
+    }
+
+    // long and double use two items in the stack,
+    // so the forms in JVMS can be handled by the number of items.
+    // duplicate the top `size` items and insert them under `skip` items
+    fn duplicate(&mut self, size: usize, skip: usize) {
+        let length = self.stack.len();
+        assert!(
+            length >= size + skip,
+            "should exist {} items in operand_stack. actual: {}",
+            size + skip,
+            length
+        );
+        let values: Vec<Item> = self.stack[length - size..].to_vec();
+        let insert_index = length - size - skip;
+        self.stack.splice(insert_index..insert_index, values);
+    }
+
+    pub fn dup_x1(&mut self) {
+        self.duplicate(1, 1);
+    }
+
+    pub fn dup_x2(&mut self) {
+        self.duplicate(1, 2);
+    }
+
+    pub fn dup2(&mut self) {
+        self.duplicate(2, 0);
+    }
+
+    pub fn dup2_x1(&mut self) {
+        self.duplicate(2, 1);
+    }
+
+    pub fn dup2_x2(&mut self) {
+        self.duplicate(2, 2);
+    }
+
+    pub fn pop2(&mut self) {
+        match (self.stack.pop(), self.stack.pop()) {
+            (Some(_), Some(_)) => {}
+            _ => unreachable!("should exist two items in operand_stack"),
+        }
+    }
+
+    pub fn swap(&mut self) {
+        let length = self.stack.len();
+        assert!(length >= 2, "should exist two items in operand_stack");
+        self.stack.swap(length - 1, length - 2);
+    }
+
     fn compare_value<T>(&self, first: T, second: T) -> Item
     where
         T: PartialOrd,
@@ -219,16 +507,16 @@ impl fmt::Display for OperandStack {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let length = self.stack.len();
         let mut item_string_vec = Vec::with_capacity(length);
-        let mut index = 0;
-        for item in self.stack.iter() {
+        let mut items = self.stack.iter().enumerate();
+        while let Some((index, item)) = items.next() {
             match item {
-                Item::Long(_) => {
+                // the next item is the second slot of the same value
+                Item::Long(_) | Item::Double(_) => {
                     item_string_vec.push(format!("#{}+#{} {}", index, index + 1, item));
-                    index += 1;
+                    items.next();
                 }
                 _ => item_string_vec.push(format!("#{} {}", index, item)),
             };
-            index += 1;
         }
 
         write!(
@@ -1,11 +1,8 @@
 use crate::attribute::instruction::Instruction;
-use crate::object::{ObjectMap, Objectref};
-use crate::operand::Item;
+use crate::operand::{create_double_items, create_long_items, Item};
 use crate::option::RJ_OPTION;
 use crate::stackframe::Stackframe;
 
-use std::cell::RefCell;
-use std::collections::HashMap;
 use std::fs::File;
 use std::io::prelude::*;
 use std::io::Result;
@@ -35,8 +32,128 @@ pub fn extract_x_byte_as_usize(input: &[u8], index: usize, x: usize) -> (usize,
     (result, index + x)
 }
 
-pub fn devide_i64_to_two_i32(input: i64) -> (i32, i32) {
-    (((input >> 32) << 32) as i32, (input & 0xFFFFFFFF) as i32)
+pub fn write_x_byte_as_usize(output: &mut Vec<u8>, value: usize, x: usize) {
+    for i in 0..x {
+        output.push((value >> ((x - i - 1) * 8)) as u8);
+    }
+}
+
+// Float.toString and Double.toString use the decimal notation only for 10^-3 <= |value| < 10^7
+pub fn java_float_to_string(value: f32) -> String {
+    if value.is_nan() || value.is_infinite() || value == 0.0 {
+        return java_special_to_string(value as f64);
+    }
+    // keep the shortest digits but round the exact value like Java when two candidates tie
+    let precision = fraction_length(&format!("{:e}", value.abs()));
+    java_scientific_to_string(
+        &format!("{:.*e}", precision, value.abs()),
+        value.is_sign_negative(),
+        (1e-3..1e7).contains(&value.abs()),
+    )
+}
+
+pub fn java_double_to_string(value: f64) -> String {
+    if value.is_nan() || value.is_infinite() || value == 0.0 {
+        return java_special_to_string(value);
+    }
+    let precision = fraction_length(&format!("{:e}", value.abs()));
+    java_scientific_to_string(
+        &format!("{:.*e}", precision, value.abs()),
+        value.is_sign_negative(),
+        (1e-3..1e7).contains(&value.abs()),
+    )
+}
+
+// the number of digits after the point in the mantissa of 1.25e-5
+fn fraction_length(scientific: &str) -> usize {
+    let mantissa = &scientific[..scientific.find('e').expect("should have e")];
+    mantissa
+        .find('.')
+        .map_or(0, |point| mantissa.len() - point - 1)
+}
+
+fn java_special_to_string(value: f64) -> String {
+    if value.is_nan() {
+        String::from("NaN")
+    } else if value.is_infinite() {
+        String::from(if value > 0.0 { "Infinity" } else { "-Infinity" })
+    } else if value.is_sign_negative() {
+        String::from("-0.0")
+    } else {
+        String::from("0.0")
+    }
+}
+
+// scientific is the representation like 1.25e-5 which rust outputs by {:e}
+fn java_scientific_to_string(scientific: &str, negative: bool, is_decimal: bool) -> String {
+    let (mantissa, exponent) = scientific.split_at(scientific.find('e').expect("should have e"));
+    let exponent: i32 = exponent[1..].parse().expect("exponent should be number");
+    let digits = mantissa.replace('.', "");
+    let sign = if negative { "-" } else { "" };
+
+    if !is_decimal {
+        let fraction = if digits.len() > 1 { &digits[1..] } else { "0" };
+        return format!("{}{}.{}E{}", sign, &digits[..1], fraction, exponent);
+    }
+    if exponent < 0 {
+        let zeros = "0".repeat((-exponent - 1) as usize);
+        return format!("{}0.{}{}", sign, zeros, digits);
+    }
+    let integer_length = exponent as usize + 1;
+    if digits.len() > integer_length {
+        format!(
+            "{}{}.{}",
+            sign,
+            &digits[..integer_length],
+            &digits[integer_length..]
+        )
+    } else {
+        let zeros = "0".repeat(integer_length - digits.len());
+        format!("{}{}{}.0", sign, digits, zeros)
+    }
+}
+
+// modified UTF-8 (JVMS 4.4.7) encodes NUL as 0xc0 0x80 and
+// supplementary characters as surrogate pairs of 3 bytes sequences
+pub fn decode_modified_utf8(bytes: &[u8]) -> String {
+    let mut units = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let x = bytes[index] as u16;
+        if x & 0x80 == 0 {
+            units.push(x);
+            index += 1;
+        } else if x & 0xe0 == 0xc0 {
+            let y = bytes[index + 1] as u16;
+            units.push(((x & 0x1f) << 6) | (y & 0x3f));
+            index += 2;
+        } else {
+            let y = bytes[index + 1] as u16;
+            let z = bytes[index + 2] as u16;
+            units.push(((x & 0x0f) << 12) | ((y & 0x3f) << 6) | (z & 0x3f));
+            index += 3;
+        }
+    }
+    String::from_utf16_lossy(&units)
+}
+
+pub fn encode_modified_utf8(value: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.len());
+    for unit in value.encode_utf16() {
+        match unit {
+            0x0001..=0x007f => bytes.push(unit as u8),
+            0x0000 | 0x0080..=0x07ff => {
+                bytes.push((0xc0 | (unit >> 6)) as u8);
+                bytes.push((0x80 | (unit & 0x3f)) as u8);
+            }
+            _ => {
+                bytes.push((0xe0 | (unit >> 12)) as u8);
+                bytes.push((0x80 | ((unit >> 6) & 0x3f)) as u8);
+                bytes.push((0x80 | (unit & 0x3f)) as u8);
+            }
+        }
+    }
+    bytes
 }
 
 pub fn emit_debug_info(instruction: &Instruction, stackframe: Option<&Stackframe>) {
@@ -62,20 +179,20 @@ pub fn iniailize_primitive_array(type_index: usize, length: usize) -> Vec<(Item,
     let default_val = match type_index {
         // TBoolean
         4 => (Item::Boolean(false), Item::Null),
-        // // TChar
-        // 5 => ,
-        // // TFloat
-        // 6 => ,
-        // // TDouble
-        // 7 => ,
-        // // TByte
-        // 8 => ,
-        // // TShort
-        // 9 => ,
+        // TChar
+        5 => (Item::Int(0), Item::Null),
+        // TFloat
+        6 => (Item::Float(0.0), Item::Null),
+        // TDouble
+        7 => create_double_items(0.0),
+        // TByte
+        8 => (Item::Int(0), Item::Null),
+        // TShort
+        9 => (Item::Int(0), Item::Null),
         // TInt
         10 => (Item::Int(0), Item::Null),
         // TLong
-        11 => (Item::Long(0), Item::Long(0)),
+        11 => create_long_items(0),
         _ => unreachable!("type_index range should 4 - 11"),
     };
     let mut initialize_vec = vec![];
@@ -83,24 +200,6 @@ pub fn iniailize_primitive_array(type_index: usize, length: usize) -> Vec<(Item,
     initialize_vec
 }
 
-pub fn initialize_objectref_array(
-    object_map: &mut ObjectMap,
-    class_name_id: usize,
-    length: usize,
-) -> Vec<usize> {
-    let mut initialize_vec = Vec::with_capacity(length);
-    for _ in 0..length {
-        let id = object_map.add(Objectref::new(
-            class_name_id,
-            RefCell::new(HashMap::new()),
-            false,
-        ));
-        initialize_vec.push(id);
-    }
-
-    initialize_vec
-}
-
 #[macro_export]
 macro_rules! add_flags {
     ($flags:expr, $num:expr, $flag:expr) => {
@@ -143,3 +242,61 @@ pub fn test_extract_x_byte_as_usize() {
     );
     assert_eq!(input[4], 5);
 }
+
+#[test]
+pub fn test_write_x_byte_as_usize() {
+    let mut output = vec![];
+    write_x_byte_as_usize(&mut output, 0x0203, 2);
+    write_x_byte_as_usize(&mut output, 0x01020304, 4);
+    assert_eq!(output, vec![2, 3, 1, 2, 3, 4]);
+
+    // negative values keep their two's complement bytes
+    let mut output = vec![];
+    write_x_byte_as_usize(&mut output, -2i32 as usize, 2);
+    assert_eq!(output, vec![0xff, 0xfe]);
+}
+
+#[test]
+pub fn test_java_double_to_string() {
+    assert_eq!(java_double_to_string(1.0), "1.0");
+    assert_eq!(java_double_to_string(-12.5), "-12.5");
+    assert_eq!(java_double_to_string(0.001), "0.001");
+    assert_eq!(java_double_to_string(0.0001), "1.0E-4");
+    assert_eq!(java_double_to_string(1.0e7), "1.0E7");
+    assert_eq!(java_double_to_string(9999999.0), "9999999.0");
+    assert_eq!(java_double_to_string(1.2345e-10), "1.2345E-10");
+    assert_eq!(java_double_to_string(-0.0), "-0.0");
+    assert_eq!(java_double_to_string(f64::NAN), "NaN");
+    assert_eq!(java_double_to_string(f64::NEG_INFINITY), "-Infinity");
+}
+
+#[test]
+pub fn test_java_float_to_string() {
+    assert_eq!(java_float_to_string(0.1), "0.1");
+    assert_eq!(java_float_to_string(3.4028235e38), "3.4028235E38");
+    assert_eq!(java_float_to_string(1.0e7), "1.0E7");
+    assert_eq!(java_float_to_string(100.0), "100.0");
+    assert_eq!(java_float_to_string(3333333.25), "3333333.2");
+}
+
+#[test]
+pub fn test_decode_modified_utf8() {
+    assert_eq!(decode_modified_utf8(&[0x61, 0xc0, 0x80, 0x62]), "a\0b");
+    assert_eq!(decode_modified_utf8(&[0x63, 0xc3, 0xa9]), "c\u{e9}");
+    assert_eq!(decode_modified_utf8(&[0xe4, 0xb8, 0xad]), "\u{4e2d}");
+    assert_eq!(
+        decode_modified_utf8(&[0xed, 0xa0, 0xbd, 0xed, 0xb8, 0x80]),
+        "\u{1f600}"
+    );
+}
+
+#[test]
+pub fn test_encode_modified_utf8() {
+    assert_eq!(encode_modified_utf8("a\0b"), vec![0x61, 0xc0, 0x80, 0x62]);
+    assert_eq!(encode_modified_utf8("c\u{e9}"), vec![0x63, 0xc3, 0xa9]);
+    assert_eq!(encode_modified_utf8("\u{4e2d}"), vec![0xe4, 0xb8, 0xad]);
+    assert_eq!(
+        encode_modified_utf8("\u{1f600}"),
+        vec![0xed, 0xa0, 0xbd, 0xed, 0xb8, 0x80]
+    );
+}
@@ -47,6 +47,16 @@ pub fn get_file_content(key: &str) -> Vec<u8> {
     ))
 }
 
+#[cfg(target_arch = "wasm32")]
+pub fn has_file(key: &str) -> bool {
+    !get_file_content_from_js(key).is_empty()
+}
+
+#[cfg(unix)]
+pub fn has_file(key: &str) -> bool {
+    std::path::Path::new(key).exists()
+}
+
 #[cfg(unix)]
 pub fn print_log(value: &str) {
     println!("{}", value);
@@ -57,3 +67,13 @@ pub fn print_log(value: &str) {
 pub fn print_log(value: &str) {
     output_log(value);
 }
+
+#[cfg(unix)]
+pub fn print_error_log(value: &str) {
+    eprintln!("{}", value);
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn print_error_log(value: &str) {
+    output_log(value);
+}
@@ -0,0 +1,28 @@
+public class ArrayLength {
+  public static void main(String[] args) {
+    int[] ints = { 3, 1, 4, 1, 5 };
+    int sum = 0;
+    for (int i = 0; i < ints.length; i++) {
+      sum += ints[i];
+    }
+    System.out.println(sum);
+
+    long[] longs = new long[4];
+    System.out.println(longs.length);
+    double[] doubles = new double[2];
+    System.out.println(doubles.length);
+    char[] chars = new char[0];
+    System.out.println(chars.length);
+
+    ArrayElementClass[] customs = new ArrayElementClass[6];
+    System.out.println(customs.length);
+
+    int[][] table = new int[3][7];
+    System.out.println(table.length);
+    System.out.println(table[2].length);
+
+    int[] small = new int[3];
+    small[3] = 1;
+    System.out.println(100);
+  }
+}
@@ -0,0 +1,25 @@
+public class BitOperation {
+  public static void main(String[] args) {
+    int a = -100;
+    int b = 0x0f0f;
+    int s = 35;
+    System.out.println(a << 3);
+    System.out.println(a >> 2);
+    System.out.println(a >>> 28);
+    System.out.println(b << s);
+    System.out.println(a & b);
+    System.out.println(a | b);
+    System.out.println(a ^ b);
+
+    long c = -100L;
+    long d = 0x0f0f0f0f0fL;
+    System.out.println(c << 40);
+    System.out.println(c >> 2);
+    System.out.println(c >>> 60);
+    System.out.println(c >>> s);
+    System.out.println(d << 67);
+    System.out.println(c & d);
+    System.out.println(c | d);
+    System.out.println(c ^ d);
+  }
+}
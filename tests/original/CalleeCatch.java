@@ -0,0 +1,42 @@
+public class CalleeCatch {
+  static int count;
+
+  static int safe(int n) {
+      try {
+          return 10 / n;
+      } catch (ArithmeticException e) {
+          return -1;
+      }
+  }
+
+  static int fin() {
+      try {
+          return 1;
+      } finally {
+          count += 1;
+      }
+  }
+
+  int scale(int n) {
+      int result;
+      try {
+          result = 100 / n;
+      } catch (ArithmeticException e) {
+          result = 0;
+      }
+      return result;
+  }
+
+  public static void main(String[] args) {
+      int before = 5;
+      System.out.println(safe(2));
+      System.out.println(safe(0));
+      System.out.println(before);
+      System.out.println(fin());
+      System.out.println(fin() + fin());
+      System.out.println(count);
+      CalleeCatch callee = new CalleeCatch();
+      System.out.println(callee.scale(4) + callee.scale(0));
+      System.out.println(before + safe(5));
+  }
+}
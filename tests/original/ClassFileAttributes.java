@@ -0,0 +1,82 @@
+import java.lang.annotation.ElementType;
+import java.lang.annotation.Retention;
+import java.lang.annotation.RetentionPolicy;
+import java.lang.annotation.Target;
+
+@ClassFileAttributes.Marker(value = 5, names = {"a", "b"}, kind = ClassFileAttributes.Kind.SECOND)
+public class ClassFileAttributes<T extends Comparable<T>> {
+  @Retention(RetentionPolicy.RUNTIME)
+  @interface Marker {
+    int value() default 3;
+
+    String[] names() default {"x"};
+
+    Kind kind() default Kind.FIRST;
+
+    Class<?> type() default Object.class;
+  }
+
+  @Retention(RetentionPolicy.CLASS)
+  @interface Hidden {}
+
+  @Target(ElementType.TYPE_USE)
+  @interface Typed {}
+
+  enum Kind {
+    FIRST,
+    SECOND
+  }
+
+  sealed interface Shape permits Square, Circle {
+    int size();
+  }
+
+  static final class Square implements Shape {
+    private final int side;
+
+    Square(int side) {
+      this.side = side;
+    }
+
+    public int size() {
+      return side * side;
+    }
+  }
+
+  static final class Circle implements Shape {
+    public int size() {
+      return 3;
+    }
+  }
+
+  @Hidden @Marker private @Typed T value;
+
+  @Deprecated
+  static int twice(@Marker final int input) throws IllegalStateException {
+    return input * 2;
+  }
+
+  private @Typed int secret() {
+    return 7;
+  }
+
+  class Inner {
+    int reveal() {
+      return secret();
+    }
+  }
+
+  int revealByInner() {
+    return new Inner().reveal();
+  }
+
+  public static void main(String[] args) {
+    System.out.println(twice(21));
+    Shape shape = new Square(4);
+    System.out.println(shape.size());
+    shape = new Circle();
+    System.out.println(shape.size());
+    ClassFileAttributes<String> outer = new ClassFileAttributes<>();
+    System.out.println(outer.revealByInner());
+  }
+}
@@ -0,0 +1,74 @@
+public class ClassInitialization {
+  static {
+    System.out.println(1);
+  }
+
+  public static void main(String[] args) {
+    System.out.println(2);
+    System.out.println(InitChild.value);
+    System.out.println(InitChild.value + InitParent.value);
+    new InitChild();
+    System.out.println(InitCycleA.a + InitCycleB.b);
+    try {
+      System.out.println(InitBroken.value);
+    } catch (ExceptionInInitializerError e) {
+      System.out.println(30);
+    }
+    try {
+      System.out.println(InitBroken.value);
+    } catch (NoClassDefFoundError e) {
+      System.out.println(e.getMessage());
+    }
+    InitLater.run();
+  }
+}
+
+class InitParent {
+  static int value = 10;
+
+  static {
+    System.out.println(3);
+  }
+}
+
+class InitChild extends InitParent {
+  static int value = InitParent.value + 1;
+
+  static {
+    System.out.println(4);
+  }
+}
+
+class InitCycleA {
+  static int a = InitCycleB.b + 1;
+
+  static {
+    System.out.println(a);
+  }
+}
+
+class InitCycleB {
+  static int b = InitCycleA.a + 20;
+
+  static {
+    System.out.println(b);
+  }
+}
+
+class InitBroken {
+  static int value = fail();
+
+  static int fail() {
+    throw new IllegalStateException("broken");
+  }
+}
+
+class InitLater {
+  static {
+    System.out.println(40);
+  }
+
+  static void run() {
+    System.out.println(41);
+  }
+}
@@ -0,0 +1,38 @@
+public class Conversion {
+  public static void main(String[] args) {
+    int i = -129;
+    long l = i;
+    float f = i;
+    double d = i;
+    System.out.println(l);
+    System.out.println(f);
+    System.out.println(d);
+    System.out.println((int) l);
+    System.out.println((float) l);
+    System.out.println((double) l);
+
+    float g = -2.75F;
+    System.out.println((int) g);
+    System.out.println((long) g);
+    System.out.println((double) g);
+
+    double e = 12345.5D;
+    System.out.println((int) e);
+    System.out.println((long) e);
+    System.out.println((float) e);
+
+    double nan = (e - e) / (e - e);
+    System.out.println((int) nan);
+    System.out.println((long) nan);
+    System.out.println((int) (e * e * e));
+    System.out.println((int) (-e * e * e));
+    System.out.println((long) (e * e * e * e * e));
+    System.out.println((int) (g / 0.0F));
+
+    int big = 200;
+    System.out.println((byte) big);
+    int c = (char) i;
+    System.out.println(c);
+    System.out.println((short) (i * 1000));
+  }
+}
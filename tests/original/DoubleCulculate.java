@@ -0,0 +1,29 @@
+public class DoubleCulculate {
+  public static void main(String[] args) {
+    double a = 7.25D;
+    double b = -2.5D;
+    System.out.println(a + b);
+    System.out.println(a - b);
+    System.out.println(a * b);
+    System.out.println(a / b);
+    System.out.println(a % b);
+    System.out.println(-a);
+
+    if (a > b) {
+      System.out.println("a > b");
+    }
+    if (b < a) {
+      System.out.println("b < a");
+    }
+    if ((a - a) / (b - b) < a) {
+      System.out.println("NaN < a");
+    } else {
+      System.out.println("NaN is not less than a");
+    }
+    if ((a - a) / (b - b) > a) {
+      System.out.println("NaN > a");
+    } else {
+      System.out.println("NaN is not greater than a");
+    }
+  }
+}
@@ -0,0 +1,96 @@
+public class ExceptionHandling {
+  public static void main(String[] args) {
+    try {
+      throw new RuntimeException("first");
+    } catch (RuntimeException e) {
+      System.out.println(e.getMessage());
+    }
+
+    try {
+      thrower(3);
+      System.out.println(100);
+    } catch (IllegalStateException e) {
+      System.out.println(e.getMessage());
+    }
+
+    try {
+      thrower(4);
+    } catch (IllegalStateException e) {
+      System.out.println(101);
+    } catch (IllegalArgumentException e) {
+      System.out.println(e.getMessage());
+    }
+
+    try {
+      thrower(1);
+    } catch (RuntimeException e) {
+      System.out.println(102);
+    }
+
+    try {
+      throw new CustomException(42);
+    } catch (Exception e) {
+      System.out.println(e.getMessage());
+    }
+
+    try {
+      try {
+        thrower(3);
+      } finally {
+        System.out.println(5);
+      }
+    } catch (RuntimeException e) {
+      System.out.println(6);
+    }
+
+    try {
+      depth(3);
+    } catch (CustomException e) {
+      System.out.println(e.code);
+    }
+
+    try {
+      int[] small = new int[2];
+      small[2] = 1;
+    } catch (ArrayIndexOutOfBoundsException e) {
+      System.out.println(e.getMessage());
+    }
+
+    try {
+      throw new Exception();
+    } catch (Throwable e) {
+      if (e.getMessage() == null) {
+        System.out.println(7);
+      }
+    }
+
+    thrower(3);
+    System.out.println(103);
+  }
+
+  static void thrower(int n) {
+    if (n == 3) {
+      throw new IllegalStateException("three");
+    }
+    if (n == 4) {
+      throw new IllegalArgumentException("four");
+    }
+    System.out.println(n);
+  }
+
+  static int depth(int n) {
+    if (n == 0) {
+      throw new CustomException(n + 8);
+    }
+    return depth(n - 1) + 1;
+  }
+}
+
+class CustomException extends RuntimeException {
+  int code;
+
+  CustomException(int code) {
+    super("custom");
+    this.code = code;
+  }
+}
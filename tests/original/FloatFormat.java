@@ -0,0 +1,16 @@
+public class FloatFormat {
+  public static void main(String[] args) {
+      float f = 1.0E7F;
+      System.out.println(f);
+      System.out.println(f / 1000000000);
+      System.out.println(f / 3);
+      System.out.println(f * 0);
+      double d = 12345678.9;
+      System.out.println(d);
+      System.out.println(d / 10);
+      System.out.println(d / 100000000000.0);
+      System.out.println(-d * 1.0E300);
+      System.out.println(d * 0 / 0);
+      System.out.println(-d * 0);
+  }
+}
@@ -0,0 +1,94 @@
+public class InheritedField {
+  public static void main(String[] args) {
+    InheritedFieldChild child = new InheritedFieldChild();
+    // defaults of the fields declared in the super classes
+    System.out.println(child.baseValue);
+    System.out.println(child.total);
+    System.out.println(child.ratio);
+    System.out.println(child.childValue);
+    if (child.name == null) {
+      System.out.println("null name");
+    }
+
+    child.baseValue = 10;
+    child.total = 20000000000L;
+    child.ratio = 1.5;
+    child.childValue = 30;
+    child.name = "child";
+    System.out.println(child.baseValue);
+    System.out.println(child.total);
+    System.out.println(child.ratio);
+    System.out.println(child.childValue);
+    System.out.println(child.name);
+
+    // the fields are shared with the super class view
+    InheritedFieldBase base = child;
+    System.out.println(base.baseValue);
+    base.baseValue = 11;
+    System.out.println(child.baseValue);
+    child.printBase();
+
+    // shadowed fields are stored separately
+    child.shadow = 1;
+    base.shadow = 2;
+    System.out.println(child.shadow);
+    System.out.println(base.shadow);
+    child.printShadow();
+
+    InheritedFieldChild initialized = new InheritedFieldChild(5, 6);
+    System.out.println(initialized.baseValue);
+    System.out.println(initialized.childValue);
+    initialized.printBase();
+
+    InheritedFieldException exception = new InheritedFieldException("message", 7);
+    System.out.println(exception.code);
+    System.out.println(exception.getMessage());
+  }
+}
+
+class InheritedFieldRoot {
+  String name;
+  long total;
+}
+
+class InheritedFieldBase extends InheritedFieldRoot {
+  int baseValue;
+  double ratio;
+  int shadow;
+
+  InheritedFieldBase() {}
+
+  InheritedFieldBase(int baseValue) {
+    this.baseValue = baseValue;
+  }
+
+  void printBase() {
+    System.out.println(baseValue);
+  }
+}
+
+class InheritedFieldChild extends InheritedFieldBase {
+  int childValue;
+  int shadow;
+
+  InheritedFieldChild() {}
+
+  InheritedFieldChild(int baseValue, int childValue) {
+    super(baseValue);
+    this.childValue = childValue;
+  }
+
+  void printShadow() {
+    System.out.println(shadow);
+    System.out.println(super.shadow);
+  }
+}
+
+class InheritedFieldException extends Exception {
+  int code;
+
+  InheritedFieldException(String message, int code) {
+    super(message);
+    this.code = code;
+  }
+}
@@ -0,0 +1,16 @@
+public class IntegerConstant {
+  static final int MAX = 2147483647;
+  static int big = 100000;
+
+  public static void main(String[] args) {
+    int min = -2147483648;
+    System.out.println(min);
+    System.out.println(MAX);
+    System.out.println(big);
+    System.out.println(big * 3 + 40000);
+    int[] values = {65536, -32769, 1 << 20};
+    for (int value : values) {
+      System.out.println(value - 123456);
+    }
+  }
+}
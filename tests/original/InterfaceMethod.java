@@ -0,0 +1,64 @@
+public class InterfaceMethod {
+  public static void main(String[] args) {
+    InterfaceMethodShape square = new InterfaceMethodSquare();
+    InterfaceMethodShape circle = new InterfaceMethodCircle();
+    System.out.println(square.area(3));
+    System.out.println(circle.area(3));
+    square.describe();
+    circle.describe();
+
+    InterfaceMethodNamed named = new InterfaceMethodSmallSquare();
+    named.printName();
+    InterfaceMethodShape small = (InterfaceMethodShape) named;
+    System.out.println(small.area(2));
+    small.describe();
+
+    InterfaceMethodNamed labeled = new InterfaceMethodLabel();
+    labeled.printName();
+
+    try {
+      InterfaceMethodShape nothing = null;
+      nothing.describe();
+    } catch (NullPointerException e) {
+      System.out.println(100);
+    }
+  }
+}
+
+interface InterfaceMethodNamed {
+  default void printName() {
+    System.out.println("named");
+  }
+}
+
+interface InterfaceMethodShape extends InterfaceMethodNamed {
+  int area(int size);
+
+  default void describe() {
+    System.out.println("shape");
+  }
+}
+
+class InterfaceMethodSquare implements InterfaceMethodShape {
+  public int area(int size) {
+    return size * size;
+  }
+
+  public void printName() {
+    System.out.println("square");
+  }
+}
+
+class InterfaceMethodCircle implements InterfaceMethodShape {
+  public int area(int size) {
+    return size * size * 3;
+  }
+
+  public void describe() {
+    System.out.println("circle");
+  }
+}
+
+class InterfaceMethodSmallSquare extends InterfaceMethodSquare {}
+
+class InterfaceMethodLabel implements InterfaceMethodNamed {}
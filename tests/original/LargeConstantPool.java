@@ -0,0 +1,169 @@
+public class LargeConstantPool {
+  static String s0() { return "str0"; }
+  static String s1() { return "str1"; }
+  static String s2() { return "str2"; }
+  static String s3() { return "str3"; }
+  static String s4() { return "str4"; }
+  static String s5() { return "str5"; }
+  static String s6() { return "str6"; }
+  static String s7() { return "str7"; }
+  static String s8() { return "str8"; }
+  static String s9() { return "str9"; }
+  static String s10() { return "str10"; }
+  static String s11() { return "str11"; }
+  static String s12() { return "str12"; }
+  static String s13() { return "str13"; }
+  static String s14() { return "str14"; }
+  static String s15() { return "str15"; }
+  static String s16() { return "str16"; }
+  static String s17() { return "str17"; }
+  static String s18() { return "str18"; }
+  static String s19() { return "str19"; }
+  static String s20() { return "str20"; }
+  static String s21() { return "str21"; }
+  static String s22() { return "str22"; }
+  static String s23() { return "str23"; }
+  static String s24() { return "str24"; }
+  static String s25() { return "str25"; }
+  static String s26() { return "str26"; }
+  static String s27() { return "str27"; }
+  static String s28() { return "str28"; }
+  static String s29() { return "str29"; }
+  static String s30() { return "str30"; }
+  static String s31() { return "str31"; }
+  static String s32() { return "str32"; }
+  static String s33() { return "str33"; }
+  static String s34() { return "str34"; }
+  static String s35() { return "str35"; }
+  static String s36() { return "str36"; }
+  static String s37() { return "str37"; }
+  static String s38() { return "str38"; }
+  static String s39() { return "str39"; }
+  static String s40() { return "str40"; }
+  static String s41() { return "str41"; }
+  static String s42() { return "str42"; }
+  static String s43() { return "str43"; }
+  static String s44() { return "str44"; }
+  static String s45() { return "str45"; }
+  static String s46() { return "str46"; }
+  static String s47() { return "str47"; }
+  static String s48() { return "str48"; }
+  static String s49() { return "str49"; }
+  static String s50() { return "str50"; }
+  static String s51() { return "str51"; }
+  static String s52() { return "str52"; }
+  static String s53() { return "str53"; }
+  static String s54() { return "str54"; }
+  static String s55() { return "str55"; }
+  static String s56() { return "str56"; }
+  static String s57() { return "str57"; }
+  static String s58() { return "str58"; }
+  static String s59() { return "str59"; }
+  static String s60() { return "str60"; }
+  static String s61() { return "str61"; }
+  static String s62() { return "str62"; }
+  static String s63() { return "str63"; }
+  static String s64() { return "str64"; }
+  static String s65() { return "str65"; }
+  static String s66() { return "str66"; }
+  static String s67() { return "str67"; }
+  static String s68() { return "str68"; }
+  static String s69() { return "str69"; }
+  static String s70() { return "str70"; }
+  static String s71() { return "str71"; }
+  static String s72() { return "str72"; }
+  static String s73() { return "str73"; }
+  static String s74() { return "str74"; }
+  static String s75() { return "str75"; }
+  static String s76() { return "str76"; }
+  static String s77() { return "str77"; }
+  static String s78() { return "str78"; }
+  static String s79() { return "str79"; }
+  static String s80() { return "str80"; }
+  static String s81() { return "str81"; }
+  static String s82() { return "str82"; }
+  static String s83() { return "str83"; }
+  static String s84() { return "str84"; }
+  static String s85() { return "str85"; }
+  static String s86() { return "str86"; }
+  static String s87() { return "str87"; }
+  static String s88() { return "str88"; }
+  static String s89() { return "str89"; }
+  static String s90() { return "str90"; }
+  static String s91() { return "str91"; }
+  static String s92() { return "str92"; }
+  static String s93() { return "str93"; }
+  static String s94() { return "str94"; }
+  static String s95() { return "str95"; }
+  static String s96() { return "str96"; }
+  static String s97() { return "str97"; }
+  static String s98() { return "str98"; }
+  static String s99() { return "str99"; }
+  static String s100() { return "str100"; }
+  static String s101() { return "str101"; }
+  static String s102() { return "str102"; }
+  static String s103() { return "str103"; }
+  static String s104() { return "str104"; }
+  static String s105() { return "str105"; }
+  static String s106() { return "str106"; }
+  static String s107() { return "str107"; }
+  static String s108() { return "str108"; }
+  static String s109() { return "str109"; }
+  static String s110() { return "str110"; }
+  static String s111() { return "str111"; }
+  static String s112() { return "str112"; }
+  static String s113() { return "str113"; }
+  static String s114() { return "str114"; }
+  static String s115() { return "str115"; }
+  static String s116() { return "str116"; }
+  static String s117() { return "str117"; }
+  static String s118() { return "str118"; }
+  static String s119() { return "str119"; }
+  static String s120() { return "str120"; }
+  static String s121() { return "str121"; }
+  static String s122() { return "str122"; }
+  static String s123() { return "str123"; }
+  static String s124() { return "str124"; }
+  static String s125() { return "str125"; }
+  static String s126() { return "str126"; }
+  static String s127() { return "str127"; }
+  static String s128() { return "str128"; }
+  static String s129() { return "str129"; }
+  static String s130() { return "str130"; }
+  static String s131() { return "str131"; }
+  static String s132() { return "str132"; }
+  static String s133() { return "str133"; }
+  static String s134() { return "str134"; }
+  static String s135() { return "str135"; }
+  static String s136() { return "str136"; }
+  static String s137() { return "str137"; }
+  static String s138() { return "str138"; }
+  static String s139() { return "str139"; }
+  static String s140() { return "str140"; }
+  static String s141() { return "str141"; }
+  static String s142() { return "str142"; }
+  static String s143() { return "str143"; }
+  static String s144() { return "str144"; }
+  static String s145() { return "str145"; }
+  static String s146() { return "str146"; }
+  static String s147() { return "str147"; }
+  static String s148() { return "str148"; }
+  static String s149() { return "str149"; }
+
+  static int numbers() {
+      int value = 1234567;
+      return value + 7654321;
+  }
+
+  static float ratio() {
+      return 2.5f;
+  }
+
+  public static void main(String[] args) {
+      System.out.println(s0());
+      System.out.println(s149());
+      System.out.println(numbers());
+      System.out.println(ratio());
+      System.out.println("last");
+  }
+}
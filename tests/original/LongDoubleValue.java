@@ -0,0 +1,76 @@
+public class LongDoubleValue {
+  static long staticLong = -7L;
+  static double staticDouble = -2.5;
+
+  public static void main(String[] args) {
+    long negative = -123456789012L;
+    System.out.println(negative);
+    System.out.println(negative * 2L);
+    System.out.println(negative / 3L);
+    System.out.println(negative + 1L);
+    long minusOne = -1L;
+    System.out.println(minusOne);
+    System.out.println(minusOne >>> 60);
+    System.out.println(minusOne >> 60);
+    System.out.println(minusOne & 0xFFFFFFFFL);
+
+    double value = -0.1;
+    System.out.println(value);
+    System.out.println(value * 3.0);
+    System.out.println(1.0);
+    System.out.println(0.0);
+
+    System.out.println(staticLong);
+    System.out.println(staticDouble);
+    staticLong = staticLong * 1000000000000L;
+    System.out.println(staticLong);
+
+    long[] longs = new long[2];
+    longs[0] = -5L;
+    longs[1] = 4294967296L;
+    System.out.println(longs[0]);
+    System.out.println(longs[1]);
+    double[] doubles = new double[2];
+    doubles[1] = -1.75;
+    System.out.println(doubles[0]);
+    System.out.println(doubles[1]);
+
+    LongDoubleValueHolder holder = new LongDoubleValueHolder();
+    holder.total = -4294967297L;
+    holder.ratio = -0.5;
+    System.out.println(holder.total);
+    System.out.println(holder.ratio);
+    System.out.println(passThrough(holder.total));
+    System.out.println(twice(holder.ratio));
+
+    int truncated = (int) negative;
+    System.out.println(truncated);
+    System.out.println((long) -3);
+    System.out.println((double) longs[0]);
+    System.out.println((long) -2.75);
+    System.out.println((float) -0.25);
+
+    if (negative < 0L) {
+      System.out.println("negative");
+    }
+    if (value < 0.0) {
+      System.out.println("negative double");
+    }
+    if (longs[0] < longs[1]) {
+      System.out.println("ordered");
+    }
+  }
+
+  static long passThrough(long value) {
+    return value;
+  }
+
+  static double twice(double value) {
+    return value * 2;
+  }
+}
+
+class LongDoubleValueHolder {
+  long total;
+  double ratio;
+}
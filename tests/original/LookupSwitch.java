@@ -0,0 +1,45 @@
+public class LookupSwitch {
+  public static void main(String[] args) {
+    int one = 1;
+    int thousand = 1000;
+    int min = one << 31;
+    int[] keys = { min, 0 - thousand * thousand, -5, 0, 7, 8, thousand * 100, min ^ -1 };
+    for (int i = 0; i < 8; i++) {
+      int key = keys[i];
+      switch (key) {
+        case -2147483648:
+          System.out.println("min");
+          break;
+        case -1000000:
+          System.out.println("minus million");
+          break;
+        case -5:
+          System.out.println("minus five");
+          break;
+        case 7:
+          System.out.println("seven");
+          break;
+        case 100000:
+          System.out.println("hundred thousand");
+          break;
+        case 2147483647:
+          System.out.println("max");
+          break;
+        default:
+          System.out.println("default");
+          break;
+      }
+    }
+
+    int prime = 31;
+    int hash = 0 - prime * prime * prime * thousand * 67;
+    switch (hash) {
+      case 1234:
+        System.out.println("wrong");
+        break;
+      case -1995997000:
+        System.out.println("negative hash");
+        break;
+    }
+  }
+}
@@ -0,0 +1,10 @@
+public class ModifiedUtf8 {
+  public static void main(String[] args) {
+    System.out.println("nul\u0000inside");
+    System.out.println("café");
+    System.out.println("中文");
+    System.out.println("smile 😀 end");
+    System.out.println("Ångström");
+    System.out.println("𝄞");
+  }
+}
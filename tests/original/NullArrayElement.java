@@ -0,0 +1,23 @@
+public class NullArrayElement {
+  int value;
+
+  public static void main(String[] args) {
+      NullArrayElement[] items = new NullArrayElement[2];
+      System.out.println(items[0] == null ? 1 : 0);
+      items[1] = new NullArrayElement();
+      items[1].value = 3;
+      System.out.println(items[1].value);
+      if (items[1] != null) {
+          System.out.println(items[0] != items[1] ? 2 : 0);
+      }
+
+      int[][] grid = new int[2][];
+      System.out.println(grid[1] == null ? 4 : 0);
+      grid[1] = new int[3];
+      System.out.println(grid[1].length);
+
+      NullArrayElement[][] matrix = new NullArrayElement[2][3];
+      System.out.println(matrix[1][2] == null ? 5 : 0);
+      System.out.println(matrix[1].length);
+  }
+}
@@ -0,0 +1,22 @@
+public class NullArrayStore {
+  int value;
+
+  public static void main(String[] args) {
+      NullArrayStore[] items = new NullArrayStore[2];
+      items[0] = new NullArrayStore();
+      items[0].value = 7;
+      System.out.println(items[0].value);
+      items[0] = null;
+      System.out.println(items[0] == null ? 1 : 0);
+
+      int[][] grid = new int[2][2];
+      grid[1] = null;
+      System.out.println(grid[1] == null ? 2 : 0);
+
+      try {
+          System.out.println(items[1].value);
+      } catch (NullPointerException e) {
+          System.out.println(3);
+      }
+  }
+}
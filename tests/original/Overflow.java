@@ -0,0 +1,68 @@
+public class Overflow {
+  public static void main(String[] args) {
+    int one = 1;
+    int min = one << 31;
+    int max = min - 1;
+    int minusOne = -1;
+    System.out.println(min);
+    System.out.println(max);
+    System.out.println(max + 1);
+    System.out.println(min - 1);
+    System.out.println(max * 2);
+    System.out.println(min * minusOne);
+    System.out.println(min / minusOne);
+    System.out.println(min % minusOne);
+    System.out.println(-min);
+
+    // the remainder has the same sign as the dividend
+    int seven = 7;
+    int three = 3;
+    System.out.println(seven % three);
+    System.out.println(-seven % three);
+    System.out.println(seven % -three);
+    System.out.println(-seven % -three);
+    System.out.println(-seven / three);
+
+    long longOne = 1L;
+    long longMin = longOne << 63;
+    long longMax = longMin - 1L;
+    long longMinusOne = -1L;
+    System.out.println(longMin);
+    System.out.println(longMax);
+    System.out.println(longMax + 1L);
+    System.out.println(longMin - 1L);
+    System.out.println(longMax * 3L);
+    System.out.println(longMin / longMinusOne);
+    System.out.println(longMin % longMinusOne);
+    System.out.println(-longMin);
+    System.out.println(10L - 3L * longOne);
+
+    long longSeven = 7L;
+    long longThree = 3L;
+    System.out.println(longSeven % longThree);
+    System.out.println(-longSeven % longThree);
+    System.out.println(longSeven % -longThree);
+    System.out.println(longSeven - longThree);
+
+    // hash code like calculation overflows on purpose
+    int hash = 0;
+    for (int i = 0; i < 20; i++) {
+      hash = 31 * hash + i * 1000;
+    }
+    System.out.println(hash);
+
+    long checksum = 1L;
+    for (int i = 0; i < 30; i++) {
+      checksum = checksum * 1000003L + i;
+    }
+    System.out.println(checksum);
+
+    int counter = max;
+    counter++;
+    System.out.println(counter);
+
+    float value = 5.5f;
+    System.out.println(-value);
+    System.out.println(value % 2.0f);
+  }
+}
@@ -0,0 +1,74 @@
+public class PrimitiveTypeArray {
+  public static void main(String[] args) {
+    byte[] bytes = new byte[3];
+    System.out.println(bytes[0]);
+    int big = 200;
+    bytes[1] = (byte) big;
+    System.out.println(bytes[1]);
+    bytes[2] = -5;
+    System.out.println(bytes[2] + bytes[1]);
+
+    char[] chars = new char[2];
+    System.out.println((int) chars[0]);
+    chars[0] = 'A';
+    int minus = -1;
+    chars[1] = (char) minus;
+    System.out.println((int) chars[0]);
+    System.out.println((int) chars[1]);
+
+    short[] shorts = new short[2];
+    System.out.println(shorts[1]);
+    int large = big * big;
+    shorts[0] = (short) large;
+    shorts[1] = -300;
+    System.out.println(shorts[0]);
+    System.out.println(shorts[1]);
+
+    float[] floats = new float[2];
+    System.out.println(floats[0]);
+    floats[1] = 2.5f;
+    floats[0] = floats[1] * 2;
+    System.out.println(floats[0]);
+
+    double[] doubles = new double[3];
+    System.out.println(doubles[2]);
+    doubles[1] = 1.25;
+    doubles[2] = doubles[1] + 2;
+    System.out.println(doubles[2]);
+    System.out.println(doubles[0]);
+
+    boolean[] booleans = new boolean[2];
+    booleans[1] = true;
+    if (!booleans[0]) {
+      System.out.println(1);
+    }
+    if (booleans[1]) {
+      System.out.println(2);
+    }
+
+    char[][] charTable = new char[2][3];
+    charTable[1][2] = 'z';
+    System.out.println((int) charTable[1][2]);
+    System.out.println((int) charTable[0][1]);
+
+    double[][] doubleTable = new double[2][2];
+    doubleTable[0][1] = 0.5;
+    System.out.println(doubleTable[0][1] + doubleTable[1][0]);
+
+    byte[][] byteTable = new byte[2][2];
+    byteTable[1][1] = (byte) large;
+    System.out.println(byteTable[1][1]);
+
+    short[][] shortTable = new short[1][2];
+    System.out.println(shortTable[0][1]);
+
+    float[][] floatTable = new float[2][1];
+    floatTable[1][0] = 1.5f;
+    System.out.println(floatTable[1][0]);
+
+    boolean[][] booleanTable = new boolean[2][2];
+    if (!booleanTable[1][1]) {
+      System.out.println(3);
+    }
+  }
+}
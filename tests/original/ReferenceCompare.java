@@ -0,0 +1,64 @@
+public class ReferenceCompare {
+  static ReferenceElement shared;
+  static int[] sharedArray;
+
+  public static void main(String[] args) {
+    ReferenceElement first = new ReferenceElement();
+    ReferenceElement second = new ReferenceElement();
+    ReferenceElement same = first;
+    if (first == same) {
+      System.out.println(1);
+    }
+    if (first != second) {
+      System.out.println(2);
+    }
+    if (first == second) {
+      System.out.println(3);
+    }
+
+    first.value = 10;
+    second.value = 10;
+    if (first != second) {
+      System.out.println(4);
+    }
+
+    ReferenceElement nothing = null;
+    if (nothing == null) {
+      System.out.println(5);
+    }
+    if (first != null) {
+      System.out.println(6);
+    }
+    if (nothing != null) {
+      System.out.println(7);
+    }
+    if (shared == null) {
+      System.out.println(8);
+    }
+    shared = second;
+    if (shared == second) {
+      System.out.println(9);
+    }
+
+    int[] left = new int[2];
+    int[] right = new int[2];
+    int[] alias = left;
+    if (left == alias) {
+      System.out.println(10);
+    }
+    if (left != right) {
+      System.out.println(11);
+    }
+    if (sharedArray == null) {
+      System.out.println(12);
+    }
+    sharedArray = right;
+    if (sharedArray != null && sharedArray == right) {
+      System.out.println(13);
+    }
+  }
+}
+
+class ReferenceElement {
+  int value;
+}
@@ -0,0 +1,96 @@
+public class RuntimeExceptions {
+  static RuntimeExceptionElement nothing;
+
+  public static void main(String[] args) {
+    int zero = 0;
+    try {
+      System.out.println(10 / zero);
+    } catch (ArithmeticException e) {
+      System.out.println(e.getMessage());
+    }
+    try {
+      System.out.println(10 % zero);
+    } catch (ArithmeticException e) {
+      System.out.println(1);
+    }
+    long longZero = zero;
+    try {
+      System.out.println(10L / longZero);
+    } catch (ArithmeticException e) {
+      System.out.println(2);
+    }
+    try {
+      System.out.println(10L % longZero);
+    } catch (RuntimeException e) {
+      System.out.println(3);
+    }
+
+    try {
+      System.out.println(nothing.value);
+    } catch (NullPointerException e) {
+      System.out.println(4);
+    }
+    try {
+      nothing.value = 1;
+    } catch (NullPointerException e) {
+      System.out.println(5);
+    }
+    int[] ints = null;
+    try {
+      System.out.println(ints.length);
+    } catch (NullPointerException e) {
+      System.out.println(6);
+    }
+    try {
+      System.out.println(ints[0]);
+    } catch (NullPointerException e) {
+      System.out.println(7);
+    }
+    try {
+      RuntimeException exception = null;
+      throw exception;
+    } catch (NullPointerException e) {
+      System.out.println(8);
+    }
+
+    int minus = -1;
+    try {
+      int[] negative = new int[minus];
+    } catch (NegativeArraySizeException e) {
+      System.out.println(e.getMessage());
+    }
+    try {
+      RuntimeExceptionElement[] negative = new RuntimeExceptionElement[minus - 1];
+    } catch (NegativeArraySizeException e) {
+      System.out.println(e.getMessage());
+    }
+    try {
+      int[][] negative = new int[2][minus - 2];
+    } catch (NegativeArraySizeException e) {
+      System.out.println(e.getMessage());
+    }
+
+    RuntimeExceptionElement[] elements = new RuntimeExceptionElement[2];
+    try {
+      System.out.println(elements[2]);
+    } catch (ArrayIndexOutOfBoundsException e) {
+      System.out.println(e.getMessage());
+    }
+    try {
+      elements[minus] = new RuntimeExceptionElement();
+    } catch (ArrayIndexOutOfBoundsException e) {
+      System.out.println(e.getMessage());
+    }
+
+    System.out.println(divide(4, 2));
+    System.out.println(divide(4, zero));
+  }
+
+  static int divide(int left, int right) {
+    return left / right;
+  }
+}
+
+class RuntimeExceptionElement {
+  int value;
+}
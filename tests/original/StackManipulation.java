@@ -0,0 +1,45 @@
+public class StackManipulation {
+  static int total;
+  static long grand;
+
+  public static void main(String[] args) {
+    int[] ints = { 1, 2, 3 };
+    int i = 1;
+    ints[i]++;
+    System.out.println(ints[1]);
+    int before = ints[i]++;
+    System.out.println(before);
+    System.out.println(ints[1]);
+
+    long[] longs = { 10L, 20L };
+    long l = longs[i]++;
+    System.out.println(l);
+    System.out.println(longs[1]);
+
+    Counter s = new Counter();
+    s.count = 5;
+    int c = s.count++;
+    System.out.println(c);
+    System.out.println(s.count);
+
+    s.big = 7L;
+    long b = s.big++;
+    System.out.println(b);
+    System.out.println(s.big);
+
+    total = 3;
+    int t = total++;
+    System.out.println(t);
+    System.out.println(total);
+
+    grand = 100L;
+    long g = grand++;
+    System.out.println(g);
+    System.out.println(grand);
+  }
+}
+
+class Counter {
+  int count;
+  long big;
+}
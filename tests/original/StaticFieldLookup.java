@@ -0,0 +1,45 @@
+public class StaticFieldLookup {
+  public static void main(String[] args) {
+    System.out.println(LookupChild.parentValue);
+    LookupChild.parentValue = 5;
+    System.out.println(LookupParent.parentValue);
+    System.out.println(LookupChild.interfaceValue.value);
+    System.out.println(LookupChild.shadowed);
+    System.out.println(LookupParent.shadowed);
+    LookupChild.shadowed = 40;
+    System.out.println(LookupParent.shadowed);
+    System.out.println(LookupChild.shadowed);
+    System.out.println(LookupChild.childValue);
+  }
+}
+
+interface LookupInterface {
+  LookupValue interfaceValue = new LookupValue(20);
+}
+
+class LookupValue {
+  int value;
+
+  LookupValue(int value) {
+    this.value = value;
+  }
+}
+
+class LookupParent {
+  static int parentValue = 3;
+  static int shadowed = 10;
+  static final String NAME = "parent";
+
+  static {
+    System.out.println(1);
+  }
+}
+
+class LookupChild extends LookupParent implements LookupInterface {
+  static int shadowed = 30;
+  static long childValue = 50;
+
+  static {
+    System.out.println(2);
+  }
+}
@@ -0,0 +1,32 @@
+public class StringSwitch {
+  static int code(String key) {
+    switch (key) {
+      case "apple":
+        return 1;
+      case "banana":
+        return 2;
+      // "Aa" and "BB" have the same hashCode
+      case "Aa":
+        return 3;
+      case "BB":
+        return 4;
+      case "caf\u00e9":
+        return 5;
+      default:
+        return 0;
+    }
+  }
+
+  public static void main(String[] args) {
+    System.out.println(code("apple"));
+    System.out.println(code("banana"));
+    System.out.println(code("Aa"));
+    System.out.println(code("BB"));
+    System.out.println(code("caf\u00e9"));
+    System.out.println(code("cherry"));
+    System.out.println(code(""));
+    System.out.println("a long string to overflow the hash code".hashCode());
+    System.out.println("apple".equals("apple") ? 1 : 0);
+    System.out.println("apple".equals("Apple") ? 1 : 0);
+  }
+}
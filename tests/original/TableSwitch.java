@@ -0,0 +1,41 @@
+public class TableSwitch {
+  public static void main(String[] args) {
+    for (int i = -3; i < 6; i++) {
+      switch (i) {
+        case 0:
+          System.out.println("zero");
+          break;
+        case 1:
+          System.out.println("one");
+          break;
+        case 2:
+          System.out.println("two");
+        case 3:
+          System.out.println("three");
+          break;
+        default:
+          System.out.println("default");
+          break;
+      }
+    }
+
+    int count = 0;
+    for (int j = -4; j < 3; j++) {
+      switch (j) {
+        case -2:
+          count += 10;
+          break;
+        case -1:
+          count += 20;
+          break;
+        case 0:
+          count += 30;
+          break;
+        case 1:
+          count += 40;
+          break;
+      }
+      System.out.println(count);
+    }
+  }
+}
@@ -0,0 +1,114 @@
+public class TypeCheck {
+  public static void main(String[] args) {
+    Object dog = new TypeCheckDog();
+    if (dog instanceof TypeCheckDog) {
+      System.out.println(1);
+    }
+    if (dog instanceof TypeCheckAnimal) {
+      System.out.println(2);
+    }
+    if (dog instanceof TypeCheckCat) {
+      System.out.println(3);
+    }
+    if (dog instanceof TypeCheckNamed) {
+      System.out.println(4);
+    }
+    if (dog instanceof TypeCheckLiving) {
+      System.out.println(5);
+    }
+
+    Object nothing = null;
+    if (nothing instanceof TypeCheckDog) {
+      System.out.println(6);
+    }
+    TypeCheckDog castedNothing = (TypeCheckDog) nothing;
+    if (castedNothing == null) {
+      System.out.println(7);
+    }
+
+    TypeCheckAnimal animal = (TypeCheckAnimal) dog;
+    if (animal == dog) {
+      System.out.println(8);
+    }
+
+    Object numbers = new int[2];
+    if (numbers instanceof int[]) {
+      System.out.println(9);
+    }
+    if (numbers instanceof long[]) {
+      System.out.println(10);
+    }
+    Object dogs = new TypeCheckDog[2];
+    if (dogs instanceof TypeCheckAnimal[]) {
+      System.out.println(11);
+    }
+    if (dogs instanceof TypeCheckNamed[]) {
+      System.out.println(12);
+    }
+    if (dogs instanceof TypeCheckCat[]) {
+      System.out.println(13);
+    }
+    if (dogs instanceof Object[]) {
+      System.out.println(14);
+    }
+    Object matrix = new int[2][3];
+    if (matrix instanceof Object[]) {
+      System.out.println(15);
+    }
+    if (matrix instanceof int[][]) {
+      System.out.println(16);
+    }
+    if (numbers instanceof Object[]) {
+      System.out.println(17);
+    }
+
+    try {
+      TypeCheckCat cat = (TypeCheckCat) dog;
+      System.out.println(18);
+    } catch (ClassCastException e) {
+      System.out.println(19);
+    }
+    try {
+      TypeCheckAnimal[] animals = (TypeCheckAnimal[]) dogs;
+      System.out.println(20);
+      TypeCheckCat[] cats = (TypeCheckCat[]) dogs;
+      System.out.println(21);
+    } catch (ClassCastException e) {
+      System.out.println(22);
+    }
+
+    Object text = "text";
+    if (text instanceof CharSequence) {
+      System.out.println(23);
+    }
+    if (text instanceof Comparable) {
+      System.out.println(24);
+    }
+    if (text instanceof java.io.Serializable) {
+      System.out.println(25);
+    }
+    if (text instanceof TypeCheckNamed) {
+      System.out.println(26);
+    }
+    CharSequence sequence = (CharSequence) text;
+    if (sequence == text) {
+      System.out.println(27);
+    }
+    try {
+      TypeCheckNamed named = (TypeCheckNamed) text;
+      System.out.println(28);
+    } catch (ClassCastException e) {
+      System.out.println(29);
+    }
+  }
+}
+
+interface TypeCheckLiving {}
+
+interface TypeCheckNamed extends TypeCheckLiving {}
+
+class TypeCheckAnimal implements TypeCheckNamed {}
+
+class TypeCheckDog extends TypeCheckAnimal {}
+
+class TypeCheckCat extends TypeCheckAnimal {}
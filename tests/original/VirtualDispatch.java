@@ -0,0 +1,110 @@
+public class VirtualDispatch {
+  public static void main(String[] args) {
+    VirtualDispatchBase base = new VirtualDispatchBase();
+    VirtualDispatchBase middle = new VirtualDispatchMiddle();
+    VirtualDispatchBase leaf = new VirtualDispatchLeaf();
+
+    base.greet();
+    middle.greet();
+    leaf.greet();
+
+    System.out.println(base.value(1));
+    System.out.println(middle.value(1));
+    System.out.println(leaf.value(1));
+
+    // inherited without override
+    leaf.common();
+    VirtualDispatchLeaf concrete = new VirtualDispatchLeaf();
+    concrete.common();
+    concrete.leafOnly();
+
+    // called through the super class
+    base.template();
+    leaf.template();
+
+    VirtualDispatchBase[] items = new VirtualDispatchBase[3];
+    items[0] = base;
+    items[1] = middle;
+    items[2] = leaf;
+    for (int i = 0; i < items.length; i++) {
+      items[i].greet();
+    }
+
+    VirtualDispatchShape shape = new VirtualDispatchSquare();
+    System.out.println(shape.area(4));
+    System.out.println(VirtualDispatchSquare.twice(5));
+
+    try {
+      VirtualDispatchBase nothing = null;
+      nothing.greet();
+    } catch (NullPointerException e) {
+      System.out.println(100);
+    }
+  }
+}
+
+class VirtualDispatchBase {
+  void greet() {
+    System.out.println("base");
+  }
+
+  int value(int x) {
+    return x + 10;
+  }
+
+  void common() {
+    System.out.println("common");
+  }
+
+  void template() {
+    greet();
+    hook();
+  }
+
+  private void hook() {
+    System.out.println("base hook");
+  }
+}
+
+class VirtualDispatchMiddle extends VirtualDispatchBase {
+  void greet() {
+    System.out.println("middle");
+  }
+
+  int value(int x) {
+    return super.value(x) + 100;
+  }
+}
+
+class VirtualDispatchLeaf extends VirtualDispatchMiddle {
+  void greet() {
+    super.greet();
+    System.out.println("leaf");
+  }
+
+  int value(int x) {
+    return super.value(x) + 1000;
+  }
+
+  void leafOnly() {
+    common();
+  }
+
+  private void hook() {
+    System.out.println("leaf hook");
+  }
+}
+
+abstract class VirtualDispatchShape {
+  abstract int area(int size);
+
+  static int twice(int x) {
+    return x * 2;
+  }
+}
+
+class VirtualDispatchSquare extends VirtualDispatchShape {
+  int area(int size) {
+    return size * size;
+  }
+}
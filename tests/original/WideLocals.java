@@ -0,0 +1,29 @@
+public class WideLocals {
+  long base = 7L;
+
+  static long twice(long a) {
+      long b = a * 2;
+      return b;
+  }
+
+  static double half(int scale, double a) {
+      double b = a / 2;
+      long c = (long) b;
+      return b + c + scale;
+  }
+
+  long add(long a, double d) {
+      long sum = base + a;
+      return sum + (long) d;
+  }
+
+  public static void main(String[] args) {
+      long value = 21L;
+      System.out.println(twice(value));
+      System.out.println(twice(twice(3L)));
+      System.out.println(half(1, 9.0));
+      WideLocals locals = new WideLocals();
+      System.out.println(locals.add(value, 2.5));
+      System.out.println(value);
+  }
+}
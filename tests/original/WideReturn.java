@@ -0,0 +1,64 @@
+public class WideReturn {
+  public static void main(String[] args) {
+    System.out.println(addLong(10000000000L, 20L));
+    System.out.println(half(5.0));
+    System.out.println(scale(1.5f));
+
+    long total = addLong(1L, 2L) + 3L;
+    System.out.println(total);
+    double sum = half(3.0) + half(1.0);
+    System.out.println(sum);
+    float product = scale(2.0f) * scale(1.0f);
+    System.out.println(product);
+
+    // the values under the returned value are kept
+    System.out.println(pick(7, addLong(4L, 5L), half(9.0)));
+
+    WideReturnHolder holder = new WideReturnHolder();
+    holder.total = 123456789012L;
+    holder.ratio = 0.25;
+    holder.scale = 4.5f;
+    System.out.println(holder.getTotal());
+    System.out.println(holder.getRatio());
+    System.out.println(holder.getScale());
+    System.out.println(holder.twice(holder.getTotal()));
+  }
+
+  static long addLong(long first, long second) {
+    return first + second;
+  }
+
+  static double half(double value) {
+    return value / 2;
+  }
+
+  static float scale(float value) {
+    return value * 3;
+  }
+
+  static long pick(int first, long second, double third) {
+    return second;
+  }
+}
+
+class WideReturnHolder {
+  long total;
+  double ratio;
+  float scale;
+
+  long getTotal() {
+    return total;
+  }
+
+  double getRatio() {
+    return ratio;
+  }
+
+  float getScale() {
+    return scale;
+  }
+
+  long twice(long value) {
+    return value + value;
+  }
+}
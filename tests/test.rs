@@ -13,6 +13,8 @@ fn main() {
     test_helper(String::from("tests/class/NewAndCallInstanceMethod"));
     test_helper(String::from("tests/class/InitializeStatic"));
     test_helper(String::from("tests/class/Switch"));
+    test_helper(String::from("tests/class/TableSwitch"));
+    test_helper(String::from("tests/class/LookupSwitch"));
     test_helper(String::from("tests/class/InstanceField"));
     test_helper(String::from("tests/class/PrimitiveArray"));
     test_helper(String::from("tests/class/CustomArray"));
@@ -24,4 +26,35 @@ fn main() {
     test_helper(String::from("tests/class/LongCulculate"));
     test_helper(String::from("tests/class/FloatCulculate"));
     test_helper(String::from("tests/class/DconstN"));
+    test_helper(String::from("tests/class/DoubleCulculate"));
+    test_helper(String::from("tests/class/Conversion"));
+    test_helper(String::from("tests/class/BitOperation"));
+    test_helper(String::from("tests/class/StackManipulation"));
+    test_helper(String::from("tests/class/PrimitiveTypeArray"));
+    test_helper(String::from("tests/class/ArrayLength"));
+    test_helper(String::from("tests/class/ReferenceCompare"));
+    test_helper(String::from("tests/class/LegacySubroutine"));
+    test_helper(String::from("tests/class/ExceptionHandling"));
+    test_helper(String::from("tests/class/RuntimeExceptions"));
+    test_helper(String::from("tests/class/TypeCheck"));
+    test_helper(String::from("tests/class/InterfaceMethod"));
+    test_helper(String::from("tests/class/VirtualDispatch"));
+    test_helper(String::from("tests/class/InheritedField"));
+    test_helper(String::from("tests/class/WideReturn"));
+    test_helper(String::from("tests/class/LongDoubleValue"));
+    test_helper(String::from("tests/class/Overflow"));
+    test_helper(String::from("tests/class/ClassInitialization"));
+    test_helper(String::from("tests/class/StaticConstant"));
+    test_helper(String::from("tests/class/StaticFieldLookup"));
+    test_helper(String::from("tests/class/IntegerConstant"));
+    test_helper(String::from("tests/class/ModifiedUtf8"));
+    test_helper(String::from("tests/class/ClassFileAttributes"));
+    test_helper(String::from("tests/class/FloatFormat"));
+    test_helper(String::from("tests/class/NullArrayElement"));
+    test_helper(String::from("tests/class/NullArrayStore"));
+    test_helper(String::from("tests/class/CalleeCatch"));
+    test_helper(String::from("tests/class/WideLocals"));
+    test_helper(String::from("tests/class/LargeConstantPool"));
+    test_helper(String::from("tests/class/JumpToStart"));
+    test_helper(String::from("tests/class/StringSwitch"));
 }